@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(version, about = "Hide and inspect messages in PNG files")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Check that a PNG file is well formed and renderable
+    Verify(VerifyArgs),
+    /// Print the chunks of a PNG file
+    Print(PrintArgs),
+    /// Hide a message in a new chunk
+    Encode(EncodeArgs),
+    /// Print the message stored in a chunk
+    Decode(DecodeArgs),
+    /// Remove a chunk from a PNG file
+    Remove(RemoveArgs),
+    /// Copy a chunk from one PNG file into another
+    Copy(CopyArgs),
+    /// Print a summary of a PNG file
+    Stats(StatsArgs),
+    /// Explain what the letters of a chunk type mean
+    Chunktype(ChunkTypeArgs),
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    pub file: PathBuf,
+    /// Print the report as JSON
+    #[arg(long)]
+    pub json: bool,
+    /// Also fail if the file has any private ancillary chunks
+    #[arg(long)]
+    pub no_private: bool,
+}
+
+#[derive(Args)]
+pub struct PrintArgs {
+    pub file: PathBuf,
+    /// Only show chunks with at least this many bytes of data
+    #[arg(long)]
+    pub min_size: Option<u32>,
+    /// Only show chunks with at most this many bytes of data
+    #[arg(long)]
+    pub max_size: Option<u32>,
+}
+
+#[derive(Args)]
+pub struct EncodeArgs {
+    pub file: PathBuf,
+    pub chunk_type: String,
+    pub message: String,
+    /// Where to write the result, defaults to overwriting `file`
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct DecodeArgs {
+    pub file: PathBuf,
+    #[arg(required_unless_present = "search")]
+    pub chunk_type: Option<String>,
+    /// Match chunk types by their letters only, ignoring the case encoded property bits
+    #[arg(long)]
+    pub ignore_flags: bool,
+    /// Print every ancillary chunk holding readable text, for when the chunk type is forgotten
+    #[arg(long, conflicts_with_all = ["chunk_type", "ignore_flags"])]
+    pub search: bool,
+}
+
+#[derive(Args)]
+pub struct RemoveArgs {
+    pub file: PathBuf,
+    pub chunk_type: String,
+    /// Match chunk types by their letters only, ignoring the case encoded property bits
+    #[arg(long)]
+    pub ignore_flags: bool,
+}
+
+#[derive(Args)]
+pub struct CopyArgs {
+    /// File to take the chunk from
+    pub src: PathBuf,
+    /// File the chunk is added to, before its IEND
+    pub dst: PathBuf,
+    pub chunk_type: String,
+}
+
+#[derive(Args)]
+pub struct StatsArgs {
+    pub file: PathBuf,
+}
+
+#[derive(Args)]
+pub struct ChunkTypeArgs {
+    pub chunk_type: String,
+}
@@ -0,0 +1,207 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::Error;
+
+/// Parsed contents of the cHRM chunk, the CIE x/y chromaticities of the white point and the
+/// RGB primaries. Values are stored as integers scaled by 100000.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Chromaticities {
+    white_x: u32,
+    white_y: u32,
+    red_x: u32,
+    red_y: u32,
+    green_x: u32,
+    green_y: u32,
+    blue_x: u32,
+    blue_y: u32,
+}
+
+impl Chromaticities {
+    pub const LENGTH: usize = 32;
+    const SCALE: f64 = 100000.0;
+
+    /// Largest value a scaled u32 can hold.
+    pub const MAX_VALUE: f64 = u32::MAX as f64 / Chromaticities::SCALE;
+
+    /// Builds chromaticities from `(x, y)` pairs, each rounded to the 1/100000 precision cHRM
+    /// stores. Fails for values that aren't finite or fall outside `0.0..=MAX_VALUE`.
+    pub fn new(
+        white: (f64, f64),
+        red: (f64, f64),
+        green: (f64, f64),
+        blue: (f64, f64),
+    ) -> Result<Chromaticities, Error> {
+        let scaled = |v: f64| -> Result<u32, Error> {
+            if !(0.0..=Chromaticities::MAX_VALUE).contains(&v) {
+                return Err(Box::new(ChromaticitiesError::InvalidValue(v)));
+            }
+            Ok((v * Chromaticities::SCALE).round() as u32)
+        };
+        Ok(Chromaticities {
+            white_x: scaled(white.0)?,
+            white_y: scaled(white.1)?,
+            red_x: scaled(red.0)?,
+            red_y: scaled(red.1)?,
+            green_x: scaled(green.0)?,
+            green_y: scaled(green.1)?,
+            blue_x: scaled(blue.0)?,
+            blue_y: scaled(blue.1)?,
+        })
+    }
+
+    pub fn white_point(&self) -> (f64, f64) {
+        Chromaticities::unscale(self.white_x, self.white_y)
+    }
+    pub fn red(&self) -> (f64, f64) {
+        Chromaticities::unscale(self.red_x, self.red_y)
+    }
+    pub fn green(&self) -> (f64, f64) {
+        Chromaticities::unscale(self.green_x, self.green_y)
+    }
+    pub fn blue(&self) -> (f64, f64) {
+        Chromaticities::unscale(self.blue_x, self.blue_y)
+    }
+
+    fn unscale(x: u32, y: u32) -> (f64, f64) {
+        (
+            x as f64 / Chromaticities::SCALE,
+            y as f64 / Chromaticities::SCALE,
+        )
+    }
+
+    pub fn to_chunk(&self) -> Chunk {
+        let data: Vec<u8> = [
+            self.white_x,
+            self.white_y,
+            self.red_x,
+            self.red_y,
+            self.green_x,
+            self.green_y,
+            self.blue_x,
+            self.blue_y,
+        ]
+        .iter()
+        .flat_map(|v| v.to_be_bytes())
+        .collect();
+        Chunk::new(ChunkType::try_from(*b"cHRM").unwrap(), data)
+    }
+}
+
+impl TryFrom<&Chunk> for Chromaticities {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if !chunk.is_type("cHRM") {
+            return Err(Box::new(ChromaticitiesError::WrongChunkType));
+        }
+        let data = chunk.data();
+        if data.len() != Chromaticities::LENGTH {
+            return Err(Box::new(ChromaticitiesError::LengthError(data.len())));
+        }
+
+        let values: Vec<u32> = data
+            .chunks_exact(4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        Ok(Chromaticities {
+            white_x: values[0],
+            white_y: values[1],
+            red_x: values[2],
+            red_y: values[3],
+            green_x: values[4],
+            green_y: values[5],
+            blue_x: values[6],
+            blue_y: values[7],
+        })
+    }
+}
+
+#[derive(Debug)]
+enum ChromaticitiesError {
+    WrongChunkType,
+    LengthError(usize),
+    InvalidValue(f64),
+}
+
+impl std::error::Error for ChromaticitiesError {}
+
+impl Display for ChromaticitiesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChromaticitiesError::WrongChunkType => write!(f, "Chunk is not a cHRM chunk!"),
+            ChromaticitiesError::LengthError(got) => {
+                write!(
+                    f,
+                    "cHRM Length Error! Expected {} bytes, Got {} bytes",
+                    Chromaticities::LENGTH,
+                    got
+                )
+            }
+            ChromaticitiesError::InvalidValue(value) => {
+                write!(
+                    f,
+                    "Invalid cHRM value {}, must be within 0 and {}",
+                    value,
+                    Chromaticities::MAX_VALUE
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn srgb() -> Chromaticities {
+        Chromaticities::new((0.3127, 0.329), (0.64, 0.33), (0.3, 0.6), (0.15, 0.06)).unwrap()
+    }
+
+    #[test]
+    fn test_chromaticities_round_trip() {
+        let chunk = srgb().to_chunk();
+        assert_eq!(chunk.length(), 32);
+
+        let chrm = Chromaticities::try_from(&chunk).unwrap();
+        assert_eq!(chrm, srgb());
+        assert_eq!(chrm.white_point(), (0.3127, 0.329));
+        assert_eq!(chrm.red(), (0.64, 0.33));
+        assert_eq!(chrm.green(), (0.3, 0.6));
+        assert_eq!(chrm.blue(), (0.15, 0.06));
+    }
+
+    #[test]
+    fn test_chromaticities_raw_values() {
+        let chunk = srgb().to_chunk();
+        assert_eq!(&chunk.data()[..8], &[0, 0, 122, 38, 0, 0, 128, 132]);
+    }
+
+    #[test]
+    fn test_invalid_chromaticities_values() {
+        let white = (0.3127, 0.329);
+        for invalid in [
+            -0.1,
+            f64::NAN,
+            f64::INFINITY,
+            Chromaticities::MAX_VALUE + 1.0,
+        ] {
+            assert!(Chromaticities::new(white, (invalid, 0.33), (0.3, 0.6), (0.15, 0.06)).is_err());
+        }
+
+        let max = Chromaticities::MAX_VALUE;
+        let chrm = Chromaticities::new(white, (max, 0.0), (0.3, 0.6), (0.15, 0.06)).unwrap();
+        assert_eq!(
+            &chrm.to_chunk().data()[8..16],
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_invalid_chromaticities_length() {
+        let chunk = Chunk::new(ChunkType::from_str("cHRM").unwrap(), vec![0; 31]);
+        assert!(Chromaticities::try_from(&chunk).is_err());
+    }
+}
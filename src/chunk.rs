@@ -1,41 +1,417 @@
 #![allow(unused_variables)]
-use std::io::{BufReader, Read};
+use std::io::{BufReader, ErrorKind, Read};
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crc::crc32::{self, Hasher32};
 
 use crate::chunk_type::ChunkType;
+use crate::compression::{self, DEFAULT_DECOMPRESSION_LIMIT};
+use crate::ihdr::Ihdr;
+use crate::phys::Phys;
+use crate::time::Time;
 use crate::Error;
 
+/// Where a chunk found by [`Chunk::locate_at`] sits in its buffer.
+pub(crate) struct ChunkSpan {
+    chunk_type: ChunkType,
+    data: Range<usize>,
+    crc: u32,
+}
+
+impl ChunkSpan {
+    /// Data bytes of the chunk.
+    pub(crate) fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Chunk {
     chunk_type: ChunkType,
     chunk_data: Vec<u8>,
+    /// CRC as read from the input, `None` for chunks built in memory.
+    stored_crc: Option<u32>,
+    /// CRC computed from the current data, filled on first use and reset when the data changes.
+    /// A `OnceLock` rather than a `OnceCell` so chunks stay `Sync`.
+    crc_cache: OnceLock<u32>,
 }
 
 impl Chunk {
     pub const METADATA_BYTES: usize = 12;
+    /// Most data bytes reserved before reading a chunk, larger chunks grow as they're read.
+    const INITIAL_CAPACITY: usize = 8192;
+
+    /// Bytes a chunk holding `data_len` bytes of data takes up in a file, for planning layouts
+    /// before building any chunks.
+    pub const fn serialized_size(data_len: usize) -> usize {
+        data_len + Chunk::METADATA_BYTES
+    }
+
     pub fn new(chunk_type: ChunkType, chunk_data: Vec<u8>) -> Chunk {
         Chunk {
             chunk_type,
             chunk_data,
+            stored_crc: None,
+            crc_cache: OnceLock::new(),
         }
     }
 
+    /// A chunk holding `message` as UTF-8, the usual way of hiding a message.
+    pub fn from_strings(chunk_type: &str, message: &str) -> Result<Chunk, Error> {
+        Ok(Chunk::new(
+            ChunkType::from_str(chunk_type)?,
+            message.as_bytes().to_vec(),
+        ))
+    }
+
+    /// Like `new` but copies the data from a borrowed buffer.
+    pub fn new_binary(chunk_type: ChunkType, data: &[u8]) -> Chunk {
+        Chunk::new(chunk_type, data.to_vec())
+    }
+
+    /// Lays out each record as its 4-byte big-endian length followed by its bytes, for storing
+    /// several values in one custom chunk. Read them back with [`Chunk::unpack_records`].
+    /// Fails for records of 4 GiB or more, whose length doesn't fit the prefix.
+    pub fn pack_records(chunk_type: ChunkType, records: &[&[u8]]) -> Result<Chunk, Error> {
+        let mut chunk_data = Vec::new();
+        for record in records {
+            chunk_data.extend_from_slice(&Chunk::record_prefix(record.len())?);
+            chunk_data.extend_from_slice(record);
+        }
+        Ok(Chunk::new(chunk_type, chunk_data))
+    }
+
+    fn record_prefix(len: usize) -> Result<[u8; 4], Error> {
+        match u32::try_from(len) {
+            Ok(len) => Ok(len.to_be_bytes()),
+            Err(_) => Err(Box::new(ChunkError::RecordTooLongError(len))),
+        }
+    }
+
+    /// Splits data written by [`Chunk::pack_records`] back into records, failing if a length
+    /// prefix or record runs past the end of the data.
+    pub fn unpack_records(&self) -> Result<Vec<Vec<u8>>, Error> {
+        let mut records = Vec::new();
+        let mut rest = self.data();
+        while !rest.is_empty() {
+            let Some((prefix, after)) = rest.split_first_chunk::<4>() else {
+                return Err(Box::new(ChunkError::LengthError(4, rest.len())));
+            };
+            let length = u32::from_be_bytes(*prefix) as usize;
+            if after.len() < length {
+                return Err(Box::new(ChunkError::LengthError(length, after.len())));
+            }
+            let (record, after) = after.split_at(length);
+            records.push(record.to_vec());
+            rest = after;
+        }
+        Ok(records)
+    }
+
+    /// Zlib-compresses `raw` at `level` (0-9) into the data of a new chunk, undone with
+    /// [`Chunk::decompressed`].
+    pub fn compressed(chunk_type: ChunkType, raw: &[u8], level: u32) -> Chunk {
+        Chunk::new(chunk_type, compression::deflate_with_level(raw, level))
+    }
+
+    /// Inflates data written by [`Chunk::compressed`] with the [`DEFAULT_DECOMPRESSION_LIMIT`].
+    pub fn decompressed(&self) -> Result<Vec<u8>, Error> {
+        self.decompressed_with_limit(DEFAULT_DECOMPRESSION_LIMIT)
+    }
+
+    /// Like [`Chunk::decompressed`] but refusing to inflate past `limit` bytes.
+    pub fn decompressed_with_limit(&self, limit: usize) -> Result<Vec<u8>, Error> {
+        compression::inflate(self.data(), limit)
+    }
+
+    /// The IEND chunk that terminates every PNG, it never carries data.
+    pub fn iend() -> Chunk {
+        Chunk::new(ChunkType::IEND, Vec::new())
+    }
+
     pub fn length(&self) -> u32 {
         self.chunk_data.len() as u32
     }
     pub fn chunk_type(&self) -> &ChunkType {
         &self.chunk_type
     }
-    fn data(&self) -> &[u8] {
+    /// Whether the chunk type is exactly `name`, which is false for names that aren't 4 bytes.
+    pub fn is_type(&self, name: &str) -> bool {
+        name.as_bytes() == self.chunk_type.bytes()
+    }
+    /// The raw data bytes, for payloads that aren't text such as zTXt.
+    pub fn data(&self) -> &[u8] {
         self.chunk_data.as_slice()
     }
-    fn crc(&self) -> u32 {
-        let bytez: Vec<u8> = self
-            .chunk_type
-            .bytes()
-            .iter()
-            .chain(self.chunk_data.iter())
-            .copied()
-            .collect();
-        crc::crc32::checksum_ieee(&bytez)
+    /// Consumes the chunk, handing back the data without copying it.
+    pub fn into_data(self) -> Vec<u8> {
+        self.chunk_data
+    }
+    /// Cheap pre-filter for diffing that only compares type and length. Chunks that are
+    /// `shallow_eq` can still hold different data, so this is not equality.
+    pub fn shallow_eq(&self, other: &Chunk) -> bool {
+        self.chunk_type == other.chunk_type && self.length() == other.length()
+    }
+    /// Mutable access to the data. The stored CRC is dropped since it may no longer match,
+    /// `crc` and `as_bytes` always compute it from the current data.
+    pub fn data_mut(&mut self) -> &mut Vec<u8> {
+        self.stored_crc = None;
+        self.crc_cache = OnceLock::new();
+        &mut self.chunk_data
+    }
+    /// Replaces the data, see [`Chunk::data_mut`].
+    pub fn set_data(&mut self, chunk_data: Vec<u8>) {
+        self.stored_crc = None;
+        self.crc_cache = OnceLock::new();
+        self.chunk_data = chunk_data;
+    }
+    /// CRC over the chunk type and data, computed from the current data once and then cached.
+    pub fn crc(&self) -> u32 {
+        *self.crc_cache.get_or_init(|| {
+            // Fed in two parts so the data isn't copied next to the type first
+            let mut digest = crc32::Digest::new(crc32::IEEE);
+            digest.write(&self.chunk_type.bytes());
+            digest.write(&self.chunk_data);
+            digest.sum32()
+        })
+    }
+
+    /// Checks the CRC computed over type and data against one from elsewhere, e.g. a protocol
+    /// header.
+    pub fn verify_crc(&self, expected: u32) -> Result<(), Error> {
+        let crc_computed = self.crc();
+        if expected != crc_computed {
+            return Err(Box::new(ChunkError::CrcMismatchError(
+                expected,
+                crc_computed,
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks the CRC read from the input against the computed one. Only for chunks that were
+    /// just parsed, a chunk without a stored CRC is an error rather than trivially valid.
+    fn check_stored_crc(&self) -> Result<(), Error> {
+        match self.stored_crc {
+            Some(stored) => self.verify_crc(stored),
+            None => Err(Box::new(ChunkError::MissingCrcError)),
+        }
+    }
+
+    /// Like [`Chunk::verify_crc`] for callers that only need a yes or no.
+    pub fn is_crc_valid(&self, expected: u32) -> bool {
+        self.crc() == expected
+    }
+
+    /// The CRC a correct encoding of this chunk carries, the same value as [`Chunk::crc`].
+    pub fn expected_crc(&self) -> u32 {
+        self.crc()
+    }
+
+    /// Whether the CRC read from the input matches the one computed from the data. Chunks
+    /// that weren't parsed, or whose data has been replaced, have no stored CRC and match.
+    pub fn crc_matches_data(&self) -> bool {
+        self.stored_crc.is_none_or(|stored| stored == self.crc())
+    }
+
+    /// CRC read from the input this chunk was parsed from, if any.
+    pub fn stored_crc(&self) -> Option<u32> {
+        self.stored_crc
+    }
+
+    /// Parses a chunk like `try_from` but keeps it even when the stored CRC doesn't match the
+    /// computed one, so damaged files can still be inspected.
+    pub fn try_from_unchecked(value: &[u8]) -> Result<Chunk, Error> {
+        Chunk::check_declared_length(value)?;
+        Chunk::read_unchecked(&mut BufReader::new(value))
+    }
+
+    /// Parses the chunk starting at `offset` in `buf`, checking its CRC, and returns it with the
+    /// offset right past its CRC where the next chunk starts.
+    pub fn parse_at(buf: &[u8], offset: usize) -> Result<(Chunk, usize), Error> {
+        let Some(mut rest) = buf.get(offset..) else {
+            return Err(Box::new(ChunkError::LengthError(offset, buf.len())));
+        };
+        Chunk::check_declared_length(rest)?;
+        let chunk = Chunk::read_unchecked(&mut rest)?;
+        chunk.check_stored_crc()?;
+        Ok((chunk, buf.len() - rest.len()))
+    }
+
+    /// Checks the chunk starting at `offset` in `buf` like [`Chunk::parse_at`] but leaves its
+    /// data in place, returning where it is and the offset right past its CRC.
+    pub(crate) fn locate_at(buf: &[u8], offset: usize) -> Result<(ChunkSpan, usize), Error> {
+        let Some(rest) = buf.get(offset..) else {
+            return Err(Box::new(ChunkError::LengthError(offset, buf.len())));
+        };
+        if rest.len() < Chunk::METADATA_BYTES {
+            return Err(Box::new(ChunkError::UnexpectedEofError));
+        }
+        // Past this the declared data and the CRC are known to fit in `rest`
+        Chunk::check_declared_length(rest)?;
+        let chunk_type = ChunkType::try_from(<[u8; 4]>::try_from(&rest[4..8])?)?;
+
+        let data_start = offset + 8;
+        let data_end = data_start + u32::from_be_bytes(rest[..4].try_into()?) as usize;
+        let stored = u32::from_be_bytes(buf[data_end..data_end + 4].try_into()?);
+        // Type and data sit next to each other, so the CRC is computed without copying
+        let computed = crc32::checksum_ieee(&buf[offset + 4..data_end]);
+        if stored != computed {
+            return Err(Box::new(ChunkError::CrcMismatchError(stored, computed)));
+        }
+
+        let span = ChunkSpan {
+            chunk_type,
+            data: data_start..data_end,
+            crc: stored,
+        };
+        Ok((span, data_end + 4))
+    }
+
+    /// Builds the chunk at `span`, copying its data out of `buf`.
+    pub(crate) fn from_span(span: &ChunkSpan, buf: &[u8]) -> Chunk {
+        Chunk::from_span_data(span, buf[span.data.clone()].to_vec())
+    }
+
+    /// Builds the chunk at `span` by moving its data to the front of `buf` and keeping the
+    /// allocation, dropping everything else in it.
+    pub(crate) fn from_span_owned(span: &ChunkSpan, mut buf: Vec<u8>) -> Chunk {
+        buf.truncate(span.data.end);
+        buf.drain(..span.data.start);
+        Chunk::from_span_data(span, buf)
+    }
+
+    fn from_span_data(span: &ChunkSpan, chunk_data: Vec<u8>) -> Chunk {
+        Chunk {
+            chunk_type: span.chunk_type,
+            chunk_data,
+            stored_crc: Some(span.crc),
+            crc_cache: OnceLock::from(span.crc),
+        }
+    }
+
+    /// Fails if `value` holds anything past the chunk's CRC, for callers that expect exactly one
+    /// chunk. The same as `try_from`, spelled out at the call site, use
+    /// [`Chunk::try_from_prefix`] to ignore trailing bytes instead.
+    pub fn try_from_exact(value: &[u8]) -> Result<Chunk, Error> {
+        Chunk::try_from(value)
+    }
+
+    /// Parses the chunk at the start of `value` checking its CRC, anything after it is ignored.
+    /// This is what stream parsers walking a buffer chunk by chunk use.
+    pub fn try_from_prefix(value: &[u8]) -> Result<Chunk, Error> {
+        let chunk = Chunk::try_from_unchecked(value)?;
+        chunk.check_stored_crc()?;
+        Ok(chunk)
+    }
+
+    /// Like [`Chunk::try_from_unchecked`] but also accepts chunk types with the reserved bit
+    /// set, which the spec sets aside for future extensions.
+    pub fn try_from_lenient(value: &[u8]) -> Result<Chunk, Error> {
+        Chunk::check_declared_length(value)?;
+        Chunk::read_with(
+            &mut BufReader::new(value),
+            ChunkType::try_from_lenient,
+            usize::MAX,
+        )
+    }
+
+    /// Reads one chunk off a stream and checks its CRC. Hitting the end of the stream before the
+    /// chunk's first byte fails with an error [`is_end_of_stream`] recognises, the clean end of
+    /// a file, while running out of bytes part way through a chunk is reported as truncation.
+    pub fn from_reader<R: Read>(reader: &mut R) -> Result<Chunk, Error> {
+        Chunk::from_reader_with_limit(reader, usize::MAX)
+    }
+
+    /// Like [`Chunk::from_reader`] but failing, before allocating, for chunks that declare more
+    /// than `max_len` bytes of data.
+    pub fn from_reader_with_limit<R: Read>(reader: &mut R, max_len: usize) -> Result<Chunk, Error> {
+        let mut first = [0; 1];
+        if reader.read(&mut first)? == 0 {
+            return Err(Box::new(ChunkError::EndOfStreamError));
+        }
+
+        let chunk = Chunk::read_with(&mut first.chain(reader), ChunkType::try_from, max_len);
+        let chunk = chunk.map_err(|e| match e.downcast_ref::<std::io::Error>() {
+            Some(io) if io.kind() == ErrorKind::UnexpectedEof => {
+                Box::new(ChunkError::UnexpectedEofError)
+            }
+            _ => e,
+        })?;
+        chunk.check_stored_crc()?;
+        Ok(chunk)
+    }
+
+    /// Fails when the length field claims more data than `value` holds, so a corrupt length
+    /// can't make us allocate gigabytes before the read fails.
+    fn check_declared_length(value: &[u8]) -> Result<(), Error> {
+        let Some(length) = value.first_chunk::<4>() else {
+            return Ok(());
+        };
+        let declared = u32::from_be_bytes(*length) as usize;
+        let available = value.len().saturating_sub(Chunk::METADATA_BYTES);
+        if declared > available {
+            return Err(Box::new(ChunkError::TruncatedError(declared, available)));
+        }
+        Ok(())
+    }
+
+    /// Reads one chunk off `reader` keeping the stored CRC without checking it.
+    pub(crate) fn read_unchecked<R: Read>(reader: &mut R) -> Result<Chunk, Error> {
+        Chunk::read_with(reader, ChunkType::try_from, usize::MAX)
+    }
+
+    /// Reads `len` data bytes into `buf`, replacing its contents. Reusing one buffer across
+    /// chunks avoids an allocation per chunk in high throughput parsers.
+    pub fn read_data_into<R: Read>(r: &mut R, len: usize, buf: &mut Vec<u8>) -> Result<(), Error> {
+        buf.clear();
+        buf.resize(len, 0);
+        r.read_exact(buf)?;
+        Ok(())
+    }
+
+    fn read_with<R: Read>(
+        reader: &mut R,
+        parse_type: impl Fn([u8; 4]) -> Result<ChunkType, Error>,
+        max_len: usize,
+    ) -> Result<Chunk, Error> {
+        // 4-byte buffer for temp reading
+        let mut buf: [u8; 4] = [0; 4];
+
+        // Length of chunk
+        reader.read_exact(&mut buf)?;
+        let length = usize::try_from(u32::from_be_bytes(buf))?;
+        if length > max_len {
+            return Err(Box::new(ChunkError::LimitExceededError(length, max_len)));
+        }
+
+        // ChunkType
+        reader.read_exact(&mut buf)?;
+        let chunk_type: ChunkType = parse_type(buf)?;
+
+        // Read through `take` rather than `read_exact` so a short read tells us how much was there.
+        // The length is untrusted, so only a bounded amount is reserved up front and the buffer
+        // grows with the bytes actually read.
+        let mut chunk_data: Vec<u8> = Vec::with_capacity(length.min(Chunk::INITIAL_CAPACITY));
+        reader
+            .by_ref()
+            .take(length as u64)
+            .read_to_end(&mut chunk_data)?;
+        if chunk_data.len() != length {
+            return Err(Box::new(ChunkError::LengthError(length, chunk_data.len())));
+        }
+
+        // Read crc
+        reader.read_exact(&mut buf)?;
+
+        Ok(Chunk {
+            chunk_type,
+            chunk_data,
+            stored_crc: Some(u32::from_be_bytes(buf)),
+            crc_cache: OnceLock::new(),
+        })
     }
 
     pub fn data_as_string(&self) -> Result<String, Error> {
@@ -45,6 +421,11 @@ impl Chunk {
         // }
         Ok(String::from_utf8(self.chunk_data.clone()).map_err(Box::new)?)
     }
+    /// Borrowing version of [`Chunk::data_as_string`], the error is a [`std::str::Utf8Error`]
+    /// whose `valid_up_to` gives the offset decoding failed at.
+    pub fn data_as_str(&self) -> Result<&str, Error> {
+        Ok(std::str::from_utf8(self.data())?)
+    }
     pub fn as_bytes(&self) -> Vec<u8> {
         self.length()
             .to_be_bytes()
@@ -55,68 +436,102 @@ impl Chunk {
             .copied()
             .collect()
     }
+    /// Human readable data, parsed fields for known chunk types and falling back to the data
+    /// as text when it isn't known or doesn't parse.
+    fn describe_data(&self) -> String {
+        let parsed = match &self.chunk_type().bytes() {
+            b"IHDR" => Ihdr::try_from(self).map(|ihdr| ihdr.to_string()).ok(),
+            b"tIME" => Time::try_from(self).map(|time| time.to_string()).ok(),
+            b"pHYs" => Phys::try_from(self).map(|phys| phys.to_string()).ok(),
+            _ => None,
+        };
+        parsed
+            .or_else(|| self.data_as_str().ok().map(str::to_string))
+            .unwrap_or_else(|| "[data]".to_string())
+    }
+}
+
+/// Chunks are equal when type and data match byte for byte, the CRC is derived from those so the
+/// one stored at parse time isn't compared.
+impl PartialEq for Chunk {
+    fn eq(&self, other: &Self) -> bool {
+        self.chunk_type == other.chunk_type && self.chunk_data == other.chunk_data
+    }
 }
 
+impl Eq for Chunk {}
+
+/// Parses exactly one chunk, failing with a `TrailingBytesError` if the slice holds more. Streams
+/// of several chunks should go through [`crate::reader::ChunkReader`] or [`Chunk::parse_at`].
 impl TryFrom<&[u8]> for Chunk {
     type Error = Error;
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let mut reader = BufReader::new(value);
-        // 4-byte buffer for temp reading
-        let mut buf: [u8; 4] = [0; 4];
+        let new_chunk = Chunk::try_from_prefix(value)?;
 
-        // Length of chunk
-        reader.read_exact(&mut buf)?;
-        let length = usize::try_from(u32::from_be_bytes(buf))?;
-
-        // ChunkType
-        reader.read_exact(&mut buf)?;
-        let chunk_type: ChunkType = ChunkType::try_from(buf)?;
-
-        // Prepare chunk_data vec and read
-
-        let mut chunk_data: Vec<u8> = vec![0; length];
-        reader.read_exact(&mut chunk_data)?;
-        if chunk_data.len() != length {
-            return Err(Box::new(ChunkError::LengthError(length, chunk_data.len())));
+        // Nothing may follow the crc
+        let used = Chunk::serialized_size(new_chunk.length() as usize);
+        if value.len() > used {
+            return Err(Box::new(ChunkError::TrailingBytesError(value.len() - used)));
         }
 
-        let new_chunk = Chunk {
-            chunk_type,
-            chunk_data,
-        };
+        Ok(new_chunk)
+    }
+}
 
-        // Read and check crc
-        reader.read_exact(&mut buf)?;
-        let crc_provided = u32::from_be_bytes(buf);
-        let crc_computed = new_chunk.crc();
+/// Lightweight `(type, data)` form for simple serialization.
+impl From<&Chunk> for (String, Vec<u8>) {
+    fn from(chunk: &Chunk) -> Self {
+        (chunk.chunk_type.to_string(), chunk.chunk_data.clone())
+    }
+}
 
-        if crc_provided != crc_computed {
-            return Err(Box::new(ChunkError::CrcMismatchError(
-                crc_provided,
-                crc_computed,
-            )));
-        }
+impl TryFrom<(&str, Vec<u8>)> for Chunk {
+    type Error = Error;
 
-        Ok(new_chunk)
+    fn try_from((chunk_type, chunk_data): (&str, Vec<u8>)) -> Result<Self, Self::Error> {
+        Ok(Chunk::new(ChunkType::from_str(chunk_type)?, chunk_data))
     }
 }
 
+/// A precision limits how many characters of the data are shown, e.g. `{:.20}`.
 impl std::fmt::Display for Chunk {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Chunk Type : {}\nData : {}",
-            self.chunk_type(),
-            self.data_as_string()
-                .unwrap_or_else(|_| "[data]".to_string())
-        )
+        let data = self.describe_data();
+        let data = match f.precision() {
+            Some(max) => data.chars().take(max).collect(),
+            None => data,
+        };
+        write!(f, "Chunk Type : {}\nData : {}", self.chunk_type(), data)
     }
 }
 
+/// Whether an error from [`Chunk::from_reader`] is the clean end of the stream rather than a
+/// corrupt or truncated chunk.
+pub fn is_end_of_stream(error: &Error) -> bool {
+    matches!(
+        error.downcast_ref::<ChunkError>(),
+        Some(ChunkError::EndOfStreamError)
+    )
+}
+
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 enum ChunkError {
     LengthError(usize, usize),
     CrcMismatchError(u32, u32),
+    TrailingBytesError(usize),
+    /// Declared and available data bytes.
+    TruncatedError(usize, usize),
+    /// Declared data bytes and the limit they exceed.
+    LimitExceededError(usize, usize),
+    /// The stream ended part way through a chunk.
+    UnexpectedEofError,
+    /// The stream ended cleanly before a chunk.
+    EndOfStreamError,
+    /// A chunk that should have come with a CRC from the input has none.
+    MissingCrcError,
+    /// Length of a record too long for its 4-byte length prefix.
+    RecordTooLongError(usize),
 }
 
 impl std::error::Error for ChunkError {}
@@ -134,6 +549,39 @@ impl std::fmt::Display for ChunkError {
             ChunkError::CrcMismatchError(expected, got) => {
                 write!(f, "CRC Mismatch Error! Expected {}, Got {}", expected, got)
             }
+            ChunkError::TruncatedError(declared, available) => {
+                write!(
+                    f,
+                    "Truncated Error! Chunk declares {} bytes of data, only {} available",
+                    declared, available
+                )
+            }
+            ChunkError::LimitExceededError(declared, limit) => {
+                write!(
+                    f,
+                    "Limit Exceeded Error! Chunk declares {} bytes of data, the limit is {}",
+                    declared, limit
+                )
+            }
+            ChunkError::UnexpectedEofError => {
+                write!(f, "Unexpected EOF Error! The stream ended inside a chunk")
+            }
+            ChunkError::EndOfStreamError => write!(f, "End of stream, no more chunks"),
+            ChunkError::MissingCrcError => write!(f, "Missing CRC Error! Chunk has no stored CRC"),
+            ChunkError::RecordTooLongError(len) => {
+                write!(
+                    f,
+                    "Record Too Long Error! {} bytes don't fit a u32 length",
+                    len
+                )
+            }
+            ChunkError::TrailingBytesError(count) => {
+                write!(
+                    f,
+                    "Trailing Bytes Error! {} bytes left after the chunk",
+                    count
+                )
+            }
         }
     }
 }
@@ -172,6 +620,132 @@ mod tests {
         assert_eq!(chunk.crc(), 2882656334);
     }
 
+    #[test]
+    fn test_new_binary_chunk() {
+        let buffer = [0xDE, 0xAD, 0xBE, 0xEF, 0x00];
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new_binary(chunk_type, &buffer[..4]);
+        assert_eq!(chunk.length(), 4);
+        assert_eq!(chunk.data(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    }
+
+    #[test]
+    fn test_chunk_from_strings() {
+        let chunk = Chunk::from_strings("ruSt", "Meet at dawn").unwrap();
+        assert_eq!(&chunk.chunk_type().to_string(), "ruSt");
+        assert_eq!(chunk.data_as_string().unwrap(), "Meet at dawn");
+
+        assert!(Chunk::from_strings("ru5t", "Meet at dawn").is_err());
+    }
+
+    #[test]
+    fn test_iend_chunk() {
+        let chunk = Chunk::iend();
+        assert_eq!(
+            chunk.as_bytes(),
+            vec![0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82]
+        );
+    }
+
+    #[test]
+    fn test_pack_records_round_trip() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let records: [&[u8]; 3] = [b"first", b"", &[0xDE, 0xAD, 0xBE, 0xEF]];
+        let chunk = Chunk::pack_records(chunk_type, &records).unwrap();
+        assert_eq!(chunk.length(), 3 * 4 + 5 + 4);
+        assert_eq!(chunk.unpack_records().unwrap(), records);
+    }
+
+    #[test]
+    fn test_record_prefix_too_long() {
+        assert_eq!(Chunk::record_prefix(u32::MAX as usize).unwrap(), [0xFF; 4]);
+        let error = Chunk::record_prefix(u32::MAX as usize + 1).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::RecordTooLongError(0x1_0000_0000))
+        ));
+    }
+
+    #[test]
+    fn test_unpack_records_overrun() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunk = Chunk::new(chunk_type, vec![0, 0, 0, 5, 1, 2]);
+        assert!(matches!(
+            chunk
+                .unpack_records()
+                .unwrap_err()
+                .downcast_ref::<ChunkError>(),
+            Some(ChunkError::LengthError(5, 2))
+        ));
+
+        let chunk = Chunk::new(chunk_type, vec![0, 0, 0, 1, 1, 0, 0]);
+        assert!(chunk.unpack_records().is_err());
+    }
+
+    #[test]
+    fn test_shallow_eq() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let first = Chunk::new(chunk_type, b"same size".to_vec());
+        let second = Chunk::new(chunk_type, b"diff data".to_vec());
+        assert!(first.shallow_eq(&second));
+        assert_ne!(first.data(), second.data());
+
+        assert!(!first.shallow_eq(&Chunk::new(chunk_type, b"longer data".to_vec())));
+        assert!(!first.shallow_eq(&Chunk::new(
+            ChunkType::from_str("RuST").unwrap(),
+            b"same size".to_vec()
+        )));
+    }
+
+    #[test]
+    fn test_read_data_into_reuses_buffer() {
+        let mut reader: &[u8] = b"first chunk datasecond";
+        let mut buf = Vec::new();
+
+        Chunk::read_data_into(&mut reader, 16, &mut buf).unwrap();
+        assert_eq!(buf, b"first chunk data");
+        let ptr = buf.as_ptr();
+
+        Chunk::read_data_into(&mut reader, 6, &mut buf).unwrap();
+        assert_eq!(buf, b"second");
+        assert_eq!(buf.as_ptr(), ptr);
+
+        assert!(Chunk::read_data_into(&mut reader, 1, &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_binary_chunk_data_and_crc() {
+        let bytes = Chunk::new(
+            ChunkType::from_str("zTXt").unwrap(),
+            vec![0x78, 0x9C, 0xFF, 0xFE, 0x00],
+        )
+        .as_bytes();
+        let chunk = Chunk::try_from(bytes.as_ref()).unwrap();
+
+        assert!(chunk.data_as_string().is_err());
+        assert_eq!(chunk.data(), &[0x78, 0x9C, 0xFF, 0xFE, 0x00]);
+        assert_eq!(Some(chunk.crc()), chunk.stored_crc());
+    }
+
+    #[test]
+    fn test_chunk_clone_eq() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.clone(), chunk);
+
+        let built = Chunk::new(*chunk.chunk_type(), chunk.data().to_vec());
+        assert_eq!(built.stored_crc(), None);
+        assert_eq!(built, chunk);
+    }
+
+    #[test]
+    fn test_chunk_different_data_ne() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        assert_ne!(
+            Chunk::new(chunk_type, b"one".to_vec()),
+            Chunk::new(chunk_type, b"two".to_vec())
+        );
+    }
+
     #[test]
     fn test_chunk_length() {
         let chunk = testing_chunk();
@@ -184,6 +758,14 @@ mod tests {
         assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
     }
 
+    #[test]
+    fn test_chunk_is_type() {
+        let chunk = testing_chunk();
+        assert!(chunk.is_type("RuSt"));
+        assert!(!chunk.is_type("rust"));
+        assert!(!chunk.is_type("xyz"));
+    }
+
     #[test]
     fn test_chunk_string() {
         let chunk = testing_chunk();
@@ -192,12 +774,141 @@ mod tests {
         assert_eq!(chunk_string, expected_chunk_string);
     }
 
+    #[test]
+    fn test_chunk_str() {
+        let chunk = testing_chunk();
+        assert_eq!(
+            chunk.data_as_str().unwrap(),
+            chunk.data_as_string().unwrap()
+        );
+
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"ok\xFFno".to_vec());
+        let error = chunk.data_as_str().err().unwrap();
+        let utf8 = error.downcast_ref::<std::str::Utf8Error>().unwrap();
+        assert_eq!(utf8.valid_up_to(), 2);
+    }
+
+    #[test]
+    fn test_chunk_into_data() {
+        let chunk = Chunk::from_strings("RuSt", "Meet at dawn").unwrap();
+        assert_eq!(chunk.into_data(), b"Meet at dawn".to_vec());
+    }
+
+    #[test]
+    fn test_chunk_serialized_size() {
+        let chunk = testing_chunk();
+        assert_eq!(Chunk::serialized_size(42), chunk.as_bytes().len());
+        assert_eq!(Chunk::serialized_size(0), Chunk::iend().as_bytes().len());
+    }
+
+    #[test]
+    fn test_chunk_compressed_round_trip() {
+        let raw = b"This is where your secret message will be!".repeat(10_000);
+        let chunk = Chunk::compressed(ChunkType::from_str("ruSt").unwrap(), &raw, 9);
+        assert!(chunk.data().len() < raw.len() / 100);
+        assert_eq!(chunk.decompressed().unwrap(), raw);
+
+        assert!(chunk.decompressed_with_limit(raw.len() - 1).is_err());
+        assert!(testing_chunk().decompressed().is_err());
+    }
+
     #[test]
     fn test_chunk_crc() {
         let chunk = testing_chunk();
         assert_eq!(chunk.crc(), 2882656334);
     }
 
+    #[test]
+    fn test_set_data_updates_crc() {
+        let mut chunk = testing_chunk();
+        assert_eq!(chunk.stored_crc(), Some(2882656334));
+
+        chunk.set_data(b"Another message".to_vec());
+        assert_eq!(chunk.stored_crc(), None);
+        assert_eq!(chunk.length(), 15);
+        let reparsed = Chunk::try_from(chunk.as_bytes().as_slice()).unwrap();
+        assert_eq!(reparsed.data(), b"Another message");
+
+        chunk.data_mut().push(b'!');
+        assert!(Chunk::try_from(chunk.as_bytes().as_slice()).is_ok());
+    }
+
+    #[test]
+    fn test_large_chunk_crc() {
+        let data: Vec<u8> = (0..4 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+        let chunk = Chunk::new(ChunkType::from_str("IDAT").unwrap(), data);
+        assert_eq!(chunk.crc(), 1777458387);
+    }
+
+    #[test]
+    fn test_crc_cache() {
+        let fresh = |chunk: &Chunk| {
+            let bytes: Vec<u8> = chunk
+                .chunk_type()
+                .bytes()
+                .into_iter()
+                .chain(chunk.data().iter().copied())
+                .collect();
+            crc::crc32::checksum_ieee(&bytes)
+        };
+        // Verifying on parse already fills the cache
+        let mut chunk = testing_chunk();
+        assert_eq!(chunk.crc_cache.get(), Some(&2882656334));
+        assert_eq!(chunk.crc(), fresh(&chunk));
+
+        chunk.set_data(b"Changed".to_vec());
+        assert_eq!(chunk.crc_cache.get(), None);
+        assert_eq!(chunk.crc(), fresh(&chunk));
+
+        chunk.data_mut().push(b'!');
+        assert_eq!(chunk.crc_cache.get(), None);
+        assert_eq!(chunk.crc(), fresh(&chunk));
+        assert_ne!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_verify_crc() {
+        let chunk = testing_chunk();
+        assert!(chunk.verify_crc(2882656334).is_ok());
+    }
+
+    #[test]
+    fn test_verify_crc_mismatch() {
+        let chunk = testing_chunk();
+        let e = chunk.verify_crc(2882656333).unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<ChunkError>(),
+            Some(ChunkError::CrcMismatchError(2882656333, 2882656334))
+        ));
+    }
+
+    #[test]
+    fn test_is_crc_valid() {
+        let chunk =
+            Chunk::from_strings("RuSt", "This is where your secret message will be!").unwrap();
+        assert_eq!(chunk.expected_crc(), 2882656334);
+        assert!(chunk.is_crc_valid(2882656334));
+        assert!(!chunk.is_crc_valid(2882656333));
+    }
+
+    #[test]
+    fn test_crc_matches_data() {
+        let bytes = testing_chunk().as_bytes();
+        assert!(Chunk::try_from_unchecked(&bytes)
+            .unwrap()
+            .crc_matches_data());
+
+        let mut corrupt = bytes.clone();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 0xFF;
+        let mut chunk = Chunk::try_from_unchecked(&corrupt).unwrap();
+        assert!(!chunk.crc_matches_data());
+
+        chunk.set_data(b"Rewritten".to_vec());
+        assert!(chunk.crc_matches_data());
+        assert!(testing_chunk().crc_matches_data());
+    }
+
     #[test]
     fn test_valid_chunk_from_bytes() {
         let data_length: u32 = 42;
@@ -246,6 +957,242 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_try_from_exact() {
+        let mut bytes = testing_chunk().as_bytes();
+        assert!(Chunk::try_from_exact(&bytes).is_ok());
+
+        bytes.extend_from_slice(&[0, 0]);
+        assert_eq!(Chunk::try_from_prefix(&bytes).unwrap(), testing_chunk());
+        let error = Chunk::try_from_exact(&bytes).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::TrailingBytesError(2))
+        ));
+    }
+
+    #[test]
+    fn test_chunk_try_from_one_trailing_byte() {
+        let mut bytes = testing_chunk().as_bytes();
+        bytes.push(0);
+        let error = Chunk::try_from(bytes.as_ref()).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::TrailingBytesError(1))
+        ));
+    }
+
+    #[test]
+    fn test_check_stored_crc() {
+        assert!(testing_chunk().check_stored_crc().is_ok());
+
+        let built = Chunk::new(*testing_chunk().chunk_type(), b"built".to_vec());
+        let error = built.check_stored_crc().err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::MissingCrcError)
+        ));
+    }
+
+    #[test]
+    fn test_chunk_locate_at() {
+        let bytes = testing_chunk().as_bytes();
+        let (span, next) = Chunk::locate_at(&bytes, 0).unwrap();
+        assert_eq!(next, bytes.len());
+        assert_eq!(Chunk::from_span(&span, &bytes), testing_chunk());
+        assert_eq!(Chunk::from_span_owned(&span, bytes), testing_chunk());
+
+        // Cut anywhere, including inside the 12 bytes of metadata of an empty chunk
+        let empty = Chunk::iend().as_bytes();
+        for len in 0..empty.len() {
+            assert!(Chunk::locate_at(&empty[..len], 0).is_err());
+        }
+        let bytes = testing_chunk().as_bytes();
+        assert!(Chunk::locate_at(&bytes[..bytes.len() - 1], 0).is_err());
+    }
+
+    #[test]
+    fn test_chunk_parse_at() {
+        let second = Chunk::new(ChunkType::from_str("RuST").unwrap(), b"second".to_vec());
+        let buf: Vec<u8> = testing_chunk()
+            .as_bytes()
+            .into_iter()
+            .chain(second.as_bytes())
+            .collect();
+
+        let (chunk, offset) = Chunk::parse_at(&buf, 0).unwrap();
+        assert_eq!(
+            chunk.data_as_string().unwrap(),
+            testing_chunk().data_as_string().unwrap()
+        );
+        assert_eq!(offset, 42 + Chunk::METADATA_BYTES);
+
+        let (chunk, offset) = Chunk::parse_at(&buf, offset).unwrap();
+        assert_eq!(&chunk.chunk_type().to_string(), "RuST");
+        assert_eq!(offset, buf.len());
+
+        assert!(Chunk::parse_at(&buf, offset).is_err());
+        assert!(Chunk::parse_at(&buf, buf.len() + 1).is_err());
+    }
+
+    #[test]
+    fn test_oversized_length_is_truncated_error() {
+        // Claims 1 GB of data with nothing but a type and crc following
+        let mut bytes = (1u32 << 30).to_be_bytes().to_vec();
+        bytes.extend_from_slice(b"RuSt");
+        bytes.extend_from_slice(&[0; 4]);
+
+        for result in [
+            Chunk::try_from(bytes.as_ref()),
+            Chunk::try_from_lenient(&bytes),
+        ] {
+            let error = result.err().unwrap();
+            assert!(matches!(
+                error.downcast_ref::<ChunkError>(),
+                Some(ChunkError::TruncatedError(1073741824, 0))
+            ));
+        }
+        assert!(Chunk::parse_at(&bytes, 0).is_err());
+    }
+
+    #[test]
+    fn test_chunk_from_reader() {
+        let chunks = [
+            testing_chunk(),
+            Chunk::new(ChunkType::from_str("RuST").unwrap(), b"second".to_vec()),
+            Chunk::iend(),
+        ];
+        let bytes: Vec<u8> = chunks.iter().flat_map(|chunk| chunk.as_bytes()).collect();
+        let mut reader = BufReader::new(bytes.as_slice());
+
+        for chunk in chunks.iter() {
+            assert_eq!(&Chunk::from_reader(&mut reader).unwrap(), chunk);
+        }
+        assert!(is_end_of_stream(
+            &Chunk::from_reader(&mut reader).err().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_chunk_from_reader_truncated() {
+        let bytes = testing_chunk().as_bytes();
+        for len in [2, bytes.len() - 1] {
+            let error = Chunk::from_reader(&mut &bytes[..len]).err().unwrap();
+            assert!(!is_end_of_stream(&error));
+            assert!(matches!(
+                error.downcast_ref::<ChunkError>(),
+                Some(ChunkError::UnexpectedEofError)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_chunk_from_reader_truncated_data() {
+        // Header and 2 of the 42 data bytes
+        let bytes = testing_chunk().as_bytes();
+        let error = Chunk::from_reader(&mut &bytes[..10]).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::LengthError(42, 2))
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Length Error! Expected 42 bytes, Got 2 bytes"
+        );
+
+        let error = Chunk::read_unchecked(&mut &bytes[..10]).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::LengthError(42, 2))
+        ));
+    }
+
+    #[test]
+    fn test_chunk_from_reader_huge_declared_length() {
+        // Claims almost 4 GB of data but holds 3 bytes, this must fail rather than abort
+        let mut bytes = 0xFFFF_FFF0u32.to_be_bytes().to_vec();
+        bytes.extend_from_slice(b"RuSt");
+        bytes.extend_from_slice(b"hey");
+
+        let error = Chunk::from_reader_with_limit(&mut bytes.as_slice(), usize::MAX)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ChunkError>(),
+            Some(ChunkError::LengthError(0xFFFF_FFF0, 3))
+        ));
+    }
+
+    #[test]
+    fn test_unchecked_chunk_keeps_bad_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from_unchecked(chunk_data.as_ref()).unwrap();
+
+        assert_eq!(chunk.stored_crc(), Some(2882656333));
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_display_ihdr_chunk() {
+        let chunk = Chunk::new(
+            ChunkType::from_str("IHDR").unwrap(),
+            vec![0, 0, 3, 32, 0, 0, 2, 88, 8, 6, 0, 0, 0],
+        );
+        assert_eq!(
+            &chunk.to_string(),
+            "Chunk Type : IHDR\nData : 800x600, 8-bit RGBA"
+        );
+    }
+
+    #[test]
+    fn test_display_text_chunk() {
+        let chunk = testing_chunk();
+        assert_eq!(
+            &chunk.to_string(),
+            "Chunk Type : RuSt\nData : This is where your secret message will be!"
+        );
+    }
+
+    #[test]
+    fn test_display_binary_chunk() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), vec![0xFF, 0xFE]);
+        assert_eq!(&chunk.to_string(), "Chunk Type : RuSt\nData : [data]");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let chunk = testing_chunk();
+        assert_eq!(
+            format!("{:.20}", chunk),
+            "Chunk Type : RuSt\nData : This is where your s"
+        );
+        assert_eq!(format!("{:.100}", chunk), chunk.to_string());
+    }
+
+    #[test]
+    fn test_chunk_tuple_round_trip() {
+        let chunk = testing_chunk();
+        let (chunk_type, data): (String, Vec<u8>) = (&chunk).into();
+        assert_eq!(chunk_type, "RuSt");
+
+        let rebuilt = Chunk::try_from((chunk_type.as_str(), data)).unwrap();
+        assert_eq!(rebuilt, chunk);
+        assert!(Chunk::try_from(("Ru1t", Vec::new())).is_err());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
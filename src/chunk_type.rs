@@ -1,7 +1,8 @@
 use crate::Error;
-use std::{fmt::Display, str::FromStr};
+use std::{ffi::CStr, fmt::Display, str::FromStr};
 
-#[derive(PartialEq, Eq, Debug)]
+/// Ordered by the type bytes, so uppercase (critical) sorts before lowercase (ancillary).
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, PartialOrd, Ord)]
 pub struct ChunkType {
     bytez: [u8; 4],
 }
@@ -20,39 +21,251 @@ impl Display for ChunkType {
 }
 
 impl ChunkType {
+    pub const IHDR: ChunkType = ChunkType::from_u32_unchecked(0x49484452);
+    pub const PLTE: ChunkType = ChunkType::from_u32_unchecked(0x504c5445);
+    pub const IDAT: ChunkType = ChunkType::from_u32_unchecked(0x49444154);
+    pub const IEND: ChunkType = ChunkType::from_u32_unchecked(0x49454e44);
+
+    /// Public chunk types defined by the PNG specification (third edition) and its registered
+    /// extensions with a short description, critical chunks first. The APNG chunks are left out
+    /// as their names have the private bit set.
+    const STANDARD: [(ChunkType, &'static str); 28] = {
+        const fn t(bytez: &[u8; 4]) -> ChunkType {
+            ChunkType::from_u32_unchecked(u32::from_be_bytes(*bytez))
+        }
+        [
+            (ChunkType::IHDR, "Image header"),
+            (ChunkType::PLTE, "Palette"),
+            (ChunkType::IDAT, "Image data"),
+            (ChunkType::IEND, "Image trailer"),
+            (t(b"tRNS"), "Transparency"),
+            (t(b"cHRM"), "Primary chromaticities and white point"),
+            (t(b"gAMA"), "Image gamma"),
+            (t(b"iCCP"), "Embedded ICC profile"),
+            (t(b"sBIT"), "Significant bits"),
+            (t(b"sRGB"), "Standard RGB colour space"),
+            (t(b"cICP"), "Coding-independent code points"),
+            (t(b"mDCV"), "Mastering display colour volume"),
+            (t(b"cLLI"), "Content light level information"),
+            (t(b"tEXt"), "Textual data"),
+            (t(b"zTXt"), "Compressed textual data"),
+            (t(b"iTXt"), "International textual data"),
+            (t(b"bKGD"), "Background colour"),
+            (t(b"hIST"), "Image histogram"),
+            (t(b"pHYs"), "Physical pixel dimensions"),
+            (t(b"sPLT"), "Suggested palette"),
+            (t(b"eXIf"), "Exif metadata"),
+            (t(b"tIME"), "Image last-modification time"),
+            (t(b"oFFs"), "Image offset"),
+            (t(b"pCAL"), "Pixel calibration"),
+            (t(b"sCAL"), "Physical scale of image subject"),
+            (t(b"gIFg"), "GIF graphic control extension"),
+            (t(b"gIFx"), "GIF application extension"),
+            (t(b"sTER"), "Stereo image indicator"),
+        ]
+    };
+
+    /// Every chunk type in the spec and its registered extensions, e.g. for listing them in
+    /// documentation or a UI.
+    pub fn standard_types() -> impl Iterator<Item = ChunkType> {
+        ChunkType::STANDARD
+            .into_iter()
+            .map(|(chunk_type, _)| chunk_type)
+    }
+
+    /// What the chunk holds, for the types in [`ChunkType::standard_types`].
+    pub fn description(&self) -> Option<&'static str> {
+        ChunkType::STANDARD
+            .iter()
+            .find(|(chunk_type, _)| chunk_type == self)
+            .map(|(_, description)| *description)
+    }
+
     pub fn bytes(&self) -> [u8; 4] {
         self.bytez
     }
 
+    /// The type bytes read as a big-endian integer, the same order they appear in a file, so
+    /// `IHDR` is `0x49484452`.
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.bytez)
+    }
+
+    /// Builds a chunk type from its big-endian integer form without validating the bytes.
+    /// Meant for constants where the value is known to be a valid chunk type.
+    pub const fn from_u32_unchecked(value: u32) -> ChunkType {
+        ChunkType {
+            bytez: value.to_be_bytes(),
+        }
+    }
+
+    /// Parses like `from_str` but with `is_allowed` deciding which bytes are accepted instead
+    /// of the spec's ASCII letters, for experimental formats that e.g. allow digits.
+    pub fn from_str_with<F: Fn(u8) -> bool>(s: &str, is_allowed: F) -> Result<ChunkType, Error> {
+        let bytez = s.as_bytes();
+        if bytez.len() != 4 {
+            return Err(Box::new(ChunkTypeError::ByteLengthError(
+                s.chars().count(),
+                bytez.len(),
+            )));
+        }
+        let bytez: [u8; 4] = [bytez[0], bytez[1], bytez[2], bytez[3]];
+
+        match bytez.iter().all(|&b| is_allowed(b)) {
+            true => Ok(ChunkType { bytez }),
+            false => Err(Box::new(ChunkTypeError::InvalidBytesError)),
+        }
+    }
+
+    /// Parses a chunk type from a null-terminated C string, for chunk types handed over FFI.
+    /// The string must be exactly 4 alphabetic bytes before the null.
+    pub fn from_cstr(s: &CStr) -> Result<ChunkType, Error> {
+        let bytez = s.to_bytes();
+        let bytez: [u8; 4] = bytez.try_into().map_err(|_| {
+            let chars = String::from_utf8_lossy(bytez).chars().count();
+            Box::new(ChunkTypeError::ByteLengthError(chars, bytez.len()))
+        })?;
+
+        let chunk = ChunkType { bytez };
+        match chunk.is_valid_characters() {
+            true => Ok(chunk),
+            false => Err(Box::new(ChunkTypeError::InvalidBytesError)),
+        }
+    }
+
+    /// Only checks the bytes are ASCII letters, so unlike `TryFrom<[u8; 4]>` a set reserved bit
+    /// is accepted.
+    pub(crate) fn try_from_lenient(bytez: [u8; 4]) -> Result<ChunkType, Error> {
+        let chunk = ChunkType { bytez };
+        match chunk.is_valid_characters() {
+            true => Ok(chunk),
+            false => Err(Box::new(ChunkTypeError::InvalidBytesError)),
+        }
+    }
+
+    /// A random ancillary, private and safe to copy chunk type, e.g. for hiding data where it
+    /// is unlikely to collide with any other tool's chunks.
+    #[cfg(feature = "rand")]
+    pub fn random_private() -> ChunkType {
+        let lower = || rand::random_range(b'a'..=b'z');
+        let upper = || rand::random_range(b'A'..=b'Z');
+        ChunkType {
+            bytez: [lower(), lower(), upper(), lower()],
+        }
+    }
+
+    /// Raw state of bit 5 (the lowercase bit) of each of the four bytes, in order: ancillary,
+    /// private, reserved and safe-to-copy.
+    pub fn property_bits(&self) -> [bool; 4] {
+        self.bytez.map(|b| is_bit_set(b, 5))
+    }
+
+    /// Whether both types have the same letters regardless of case, i.e. they only differ in
+    /// their property bits.
+    pub fn same_base(&self, other: &ChunkType) -> bool {
+        self.bytez.eq_ignore_ascii_case(&other.bytez)
+    }
+
     /// Returns the is critical of this [`ChunkType`].
     /// Critical chunks have their 5th bit of first byte be un-set (0) instead of set (1) which
     /// indicates ancillary.
-    fn is_critical(&self) -> bool {
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use pngmehd::chunk_type::ChunkType;
+    ///
+    /// let idat = ChunkType::from_str("IDAT").unwrap();
+    /// assert!(idat.is_critical());
+    /// let rust = ChunkType::from_str("ruSt").unwrap();
+    /// assert!(!rust.is_critical());
+    /// ```
+    pub fn is_critical(&self) -> bool {
         !is_bit_set(self.bytez[0], 5)
     }
 
     /// Returns the inverse of private flag
     /// Public chunks are signified by 5th bit of second byte to be un-set(0) instead of set (1)
     /// which indicates private
-    fn is_public(&self) -> bool {
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use pngmehd::chunk_type::ChunkType;
+    ///
+    /// let idat = ChunkType::from_str("IDAT").unwrap();
+    /// assert!(idat.is_public());
+    /// let rust = ChunkType::from_str("ruSt").unwrap();
+    /// assert!(!rust.is_public());
+    /// ```
+    pub fn is_public(&self) -> bool {
         !is_bit_set(self.bytez[1], 5)
     }
 
     /// 5th bit is 0 for third bytes to be reserved
-    fn is_reserved_bit_valid(&self) -> bool {
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use pngmehd::chunk_type::ChunkType;
+    ///
+    /// assert!(ChunkType::from_str("ruSt").unwrap().is_reserved_bit_valid());
+    /// assert!(!ChunkType::from_str("Rust").unwrap().is_reserved_bit_valid());
+    /// ```
+    pub fn is_reserved_bit_valid(&self) -> bool {
         !is_bit_set(self.bytez[2], 5)
     }
 
     /// 5th bit is set (1) for safe to copy
-    fn is_safe_to_copy(&self) -> bool {
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use pngmehd::chunk_type::ChunkType;
+    ///
+    /// assert!(!ChunkType::from_str("IDAT").unwrap().is_safe_to_copy());
+    /// assert!(ChunkType::from_str("ruSt").unwrap().is_safe_to_copy());
+    /// ```
+    pub fn is_safe_to_copy(&self) -> bool {
         is_bit_set(self.bytez[3], 5)
     }
 
-    fn is_valid(&self) -> bool {
-        let validChars = self.is_valid_characters(); 
-        let validBit = self.is_reserved_bit_valid();
-        println!("Is valid chars {validChars}, validBit {validBit}");
-        
+    /// Copy with the first letter uppercase.
+    pub fn make_critical(&self) -> ChunkType {
+        self.with_property_bit(0, false)
+    }
+    /// Copy with the first letter lowercase.
+    pub fn make_ancillary(&self) -> ChunkType {
+        self.with_property_bit(0, true)
+    }
+    /// Copy with the second letter uppercase.
+    pub fn make_public(&self) -> ChunkType {
+        self.with_property_bit(1, false)
+    }
+    /// Copy with the second letter lowercase.
+    pub fn make_private(&self) -> ChunkType {
+        self.with_property_bit(1, true)
+    }
+    /// Copy with the fourth letter lowercase.
+    pub fn make_safe_to_copy(&self) -> ChunkType {
+        self.with_property_bit(3, true)
+    }
+    /// Copy with the fourth letter uppercase.
+    pub fn make_unsafe_to_copy(&self) -> ChunkType {
+        self.with_property_bit(3, false)
+    }
+
+    /// Sets or clears bit 5 of byte `index`. Only letters are touched, flipping the bit on a
+    /// digit would turn it into a control character.
+    fn with_property_bit(&self, index: usize, set: bool) -> ChunkType {
+        let mut bytez = self.bytez;
+        if bytez[index].is_ascii_alphabetic() {
+            bytez[index] = match set {
+                true => bytez[index].to_ascii_lowercase(),
+                false => bytez[index].to_ascii_uppercase(),
+            };
+        }
+        ChunkType { bytez }
+    }
+
+    /// Letters only and the reserved bit unset, what parsing a chunk type requires.
+    pub fn is_valid(&self) -> bool {
         self.is_valid_characters() && self.is_reserved_bit_valid()
     }
 
@@ -83,30 +296,50 @@ impl TryFrom<[u8; 4]> for ChunkType {
     }
 }
 
+/// Big-endian, see [`ChunkType::to_u32`].
+impl TryFrom<u32> for ChunkType {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        ChunkType::try_from(value.to_be_bytes())
+    }
+}
+
+impl From<ChunkType> for [u8; 4] {
+    fn from(chunk_type: ChunkType) -> Self {
+        chunk_type.bytez
+    }
+}
+
 impl FromStr for ChunkType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytez = s.as_bytes();
-        if bytez.len() != 4 {
-            return Err(Box::new(ChunkTypeError::ByteLengthError(bytez.len())));
-        }
-        let bytez: [u8; 4] = [bytez[0], bytez[1], bytez[2], bytez[3]];
-
-        let chunk = ChunkType { bytez };
+        ChunkType::from_str_with(s, |b| b.is_ascii_alphabetic())
+    }
+}
 
-        println!("{}", chunk);
+/// Serialized as the 4 character string, e.g. `"IHDR"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChunkType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
 
-        match chunk.is_valid_characters() {
-            true => Ok(chunk),
-            false => Err(Box::new(ChunkTypeError::InvalidBytesError)),
-        }
+/// Parsed with [`ChunkType::from_str`] so invalid strings are rejected.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChunkType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        ChunkType::from_str(&s).map_err(serde::de::Error::custom)
     }
 }
 
 #[derive(Debug)]
 enum ChunkTypeError {
-    ByteLengthError(usize),
+    /// Characters and bytes in the rejected input, which differ for non-ASCII text.
+    ByteLengthError(usize, usize),
     InvalidBytesError,
 }
 
@@ -115,8 +348,13 @@ impl std::error::Error for ChunkTypeError {}
 impl Display for ChunkTypeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ChunkTypeError::ByteLengthError(size) => {
-                write!(f, "Expected 4 bytes but got {} instead.", size)
+            ChunkTypeError::ByteLengthError(chars, bytes) => {
+                write!(
+                    f,
+                    "Chunk types must be exactly 4 ASCII characters, got {} characters ({} bytes) \
+                     instead.",
+                    chars, bytes
+                )
             }
             ChunkTypeError::InvalidBytesError => {
                 write!(f, "Bytes are invalid as a chunk type!")
@@ -128,6 +366,7 @@ impl Display for ChunkTypeError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chunk::Chunk;
     use std::convert::TryFrom;
     use std::str::FromStr;
 
@@ -158,6 +397,14 @@ mod tests {
         assert!(!chunk.is_critical());
     }
 
+    #[test]
+    pub fn test_chunk_type_idat_properties() {
+        let idat = ChunkType::from_str("IDAT").unwrap();
+        assert!(idat.is_critical() && idat.is_public() && !idat.is_safe_to_copy());
+        let rust = ChunkType::from_str("ruSt").unwrap();
+        assert!(!rust.is_critical() && !rust.is_public() && rust.is_safe_to_copy());
+    }
+
     #[test]
     pub fn test_chunk_type_is_public() {
         let chunk = ChunkType::from_str("RUSt").unwrap();
@@ -194,6 +441,35 @@ mod tests {
         assert!(!chunk.is_safe_to_copy());
     }
 
+    #[test]
+    pub fn test_chunk_type_property_bits() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        assert_eq!(chunk.property_bits(), [false, true, false, true]);
+
+        let chunk = ChunkType::from_str("IHDR").unwrap();
+        assert_eq!(chunk.property_bits(), [false; 4]);
+    }
+
+    #[test]
+    pub fn test_chunk_type_same_base() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        assert!(chunk.same_base(&ChunkType::from_str("ruST").unwrap()));
+        assert!(chunk.same_base(&chunk));
+        assert!(!chunk.same_base(&ChunkType::from_str("RuSa").unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    pub fn test_random_private() {
+        for _ in 0..32 {
+            let chunk = ChunkType::random_private();
+            assert!(chunk.is_valid());
+            assert!(!chunk.is_critical());
+            assert!(!chunk.is_public());
+            assert!(chunk.is_safe_to_copy());
+        }
+    }
+
     #[test]
     pub fn test_valid_chunk_is_valid() {
         let chunk = ChunkType::from_str("RuSt").unwrap();
@@ -209,12 +485,216 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    pub fn test_chunk_type_from_str_with_digits() {
+        let chunk = ChunkType::from_str_with("Ru1t", |b| b.is_ascii_alphanumeric()).unwrap();
+        assert_eq!(&chunk.to_string(), "Ru1t");
+
+        assert!(ChunkType::from_str_with("Ru_t", |b| b.is_ascii_alphanumeric()).is_err());
+        assert!(ChunkType::from_str_with("Ru1", |b| b.is_ascii_alphanumeric()).is_err());
+    }
+
+    #[test]
+    pub fn test_chunk_type_from_multibyte_str() {
+        let error = ChunkType::from_str("Rüst").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ChunkTypeError>(),
+            Some(ChunkTypeError::ByteLengthError(4, 5))
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Chunk types must be exactly 4 ASCII characters, got 4 characters (5 bytes) instead."
+        );
+    }
+
     #[test]
     pub fn test_chunk_type_string() {
         let chunk = ChunkType::from_str("RuSt").unwrap();
         assert_eq!(&chunk.to_string(), "RuSt");
     }
 
+    #[test]
+    pub fn test_chunk_type_from_cstr() {
+        let chunk = ChunkType::from_cstr(c"RuSt").unwrap();
+        assert_eq!(&chunk.to_string(), "RuSt");
+    }
+
+    #[test]
+    pub fn test_chunk_type_from_cstr_too_long() {
+        let chunk = ChunkType::from_cstr(c"RuStY");
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    pub fn test_chunk_type_from_cstr_invalid_chars() {
+        let chunk = ChunkType::from_cstr(c"Ru1t");
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    pub fn test_chunk_type_to_u32() {
+        assert_eq!(ChunkType::from_str("IHDR").unwrap().to_u32(), 0x49484452);
+        assert_eq!(ChunkType::from_str("tEXt").unwrap().to_u32(), 0x74455874);
+        for chunk_type in ChunkType::standard_types() {
+            // The first letter is the most significant byte
+            let expected = chunk_type
+                .to_string()
+                .bytes()
+                .fold(0u32, |value, b| (value << 8) | b as u32);
+            assert_eq!(chunk_type.to_u32(), expected, "{}", chunk_type);
+        }
+    }
+
+    #[test]
+    pub fn test_chunk_type_into_bytes() {
+        let chunk_type = ChunkType::try_from(*b"RuSt").unwrap();
+        let bytes: [u8; 4] = chunk_type.into();
+        assert_eq!(&bytes, b"RuSt");
+        assert_eq!(
+            ChunkType::try_from(bytes).unwrap(),
+            ChunkType::from_str("RuSt").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_chunk_type_copy() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let copy = chunk_type;
+        let first = Chunk::new(chunk_type, b"first".to_vec());
+        let second = Chunk::new(copy, b"second".to_vec());
+        assert_eq!(first.chunk_type(), second.chunk_type());
+        assert_eq!(chunk_type, copy);
+    }
+
+    #[test]
+    pub fn test_chunk_type_btree_map_order() {
+        let mut counts = std::collections::BTreeMap::new();
+        for name in ["tEXt", "IDAT", "IHDR", "IDAT", "IEND"] {
+            *counts
+                .entry(ChunkType::from_str(name).unwrap())
+                .or_insert(0) += 1;
+        }
+        let sorted: Vec<(String, i32)> = counts
+            .into_iter()
+            .map(|(chunk_type, count)| (chunk_type.to_string(), count))
+            .collect();
+        assert_eq!(
+            sorted,
+            vec![
+                ("IDAT".to_string(), 2),
+                ("IEND".to_string(), 1),
+                ("IHDR".to_string(), 1),
+                ("tEXt".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_chunk_type_hash_set_dedup() {
+        let types: std::collections::HashSet<ChunkType> = ["RuSt", "RuSt", "ruSt"]
+            .iter()
+            .map(|name| ChunkType::from_str(name).unwrap())
+            .collect();
+        assert_eq!(types.len(), 2);
+        assert!(types.contains(&ChunkType::from_str("ruSt").unwrap()));
+    }
+
+    #[test]
+    pub fn test_chunk_type_constants() {
+        for (chunk_type, name) in [
+            (ChunkType::IHDR, "IHDR"),
+            (ChunkType::PLTE, "PLTE"),
+            (ChunkType::IDAT, "IDAT"),
+            (ChunkType::IEND, "IEND"),
+        ] {
+            assert_eq!(chunk_type.to_string(), name);
+            assert!(chunk_type.is_valid());
+        }
+    }
+
+    #[test]
+    pub fn test_chunk_type_make_critical() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap().make_critical();
+        assert_eq!(chunk_type.to_string(), "RuSt");
+        assert!(chunk_type.is_critical());
+        assert_eq!(chunk_type.make_critical(), chunk_type);
+    }
+
+    #[test]
+    pub fn test_chunk_type_make_property_bits() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        assert!(!chunk_type.make_ancillary().is_critical());
+        assert!(chunk_type.make_public().is_public());
+        assert!(!chunk_type.make_private().is_public());
+        assert!(chunk_type.make_safe_to_copy().is_safe_to_copy());
+        assert!(!chunk_type.make_unsafe_to_copy().is_safe_to_copy());
+        assert_eq!(
+            chunk_type.make_public().make_unsafe_to_copy().to_string(),
+            "RUST"
+        );
+    }
+
+    #[test]
+    pub fn test_chunk_type_make_keeps_digits() {
+        let chunk_type = ChunkType::from_str_with("1uSt", |b| b.is_ascii_alphanumeric()).unwrap();
+        assert_eq!(chunk_type.make_ancillary().to_string(), "1uSt");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_chunk_type_serde() {
+        let chunk_type = ChunkType::from_str("tEXt").unwrap();
+        let json = serde_json::to_string(&chunk_type).unwrap();
+        assert_eq!(json, "\"tEXt\"");
+        assert_eq!(
+            serde_json::from_str::<ChunkType>(&json).unwrap(),
+            chunk_type
+        );
+
+        assert!(serde_json::from_str::<ChunkType>("\"12\"").is_err());
+    }
+
+    #[test]
+    pub fn test_chunk_type_standard_types() {
+        let standard: Vec<ChunkType> = ChunkType::standard_types().collect();
+        assert_eq!(
+            standard[..4],
+            [
+                ChunkType::IHDR,
+                ChunkType::PLTE,
+                ChunkType::IDAT,
+                ChunkType::IEND
+            ]
+        );
+        assert_eq!(standard.len(), 28);
+        for chunk_type in standard {
+            assert!(chunk_type.is_valid() && chunk_type.is_public());
+        }
+    }
+
+    #[test]
+    pub fn test_chunk_type_description() {
+        assert_eq!(ChunkType::IHDR.description(), Some("Image header"));
+        assert_eq!(ChunkType::from_str("RuSt").unwrap().description(), None);
+        assert!(ChunkType::standard_types().all(|chunk_type| chunk_type.description().is_some()));
+    }
+
+    #[test]
+    pub fn test_chunk_type_from_u32() {
+        assert_eq!(ChunkType::from_u32_unchecked(0x49484452), ChunkType::IHDR);
+        for chunk_type in ChunkType::standard_types() {
+            let value = chunk_type.to_u32();
+            assert_eq!(ChunkType::try_from(value).unwrap(), chunk_type);
+            assert_eq!(ChunkType::from_u32_unchecked(value), chunk_type);
+        }
+    }
+
+    #[test]
+    pub fn test_chunk_type_from_invalid_u32() {
+        // "Ru1t"
+        assert!(ChunkType::try_from(0x52753174u32).is_err());
+    }
+
     #[test]
     pub fn test_chunk_type_trait_impls() {
         let chunk_type_1: ChunkType = TryFrom::try_from([82, 117, 83, 116]).unwrap();
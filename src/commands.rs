@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde_json::json;
+
+use crate::arg::{
+    ChunkTypeArgs, CopyArgs, DecodeArgs, EncodeArgs, PrintArgs, RemoveArgs, StatsArgs, VerifyArgs,
+};
+use pngmehd::chunk::Chunk;
+use pngmehd::chunk_type::ChunkType;
+use pngmehd::png::{problem_kind, Png};
+use pngmehd::{Error, Result};
+
+/// Runs the renderability checks on a file, failing when any problem is found.
+/// `--no-private` also treats private ancillary chunks as errors.
+/// With `--json` the report is printed as `{ valid, errors, warnings }` where each entry has a
+/// `kind` and a `message`, the schema is kept stable for scripts to rely on.
+pub fn verify(args: &VerifyArgs) -> Result<()> {
+    let bytes = fs::read(&args.file)?;
+    let (errors, warnings): (Vec<Error>, Vec<Error>) = match Png::parse_lenient(&bytes) {
+        Ok((png, warnings)) => {
+            let mut errors = png.assert_renderable().err().unwrap_or_default();
+            if args.no_private {
+                errors.extend(png.assert_no_private_chunks().err());
+            }
+            (errors, warnings.into_iter().map(Error::from).collect())
+        }
+        Err(e) => (vec![e], Vec::new()),
+    };
+    let valid = errors.is_empty();
+
+    if args.json {
+        let entries = |problems: &[Error]| {
+            problems
+                .iter()
+                .map(|e| json!({ "kind": problem_kind(e), "message": e.to_string() }))
+                .collect::<Vec<_>>()
+        };
+        let report = json!({
+            "valid": valid,
+            "errors": entries(&errors),
+            "warnings": entries(&warnings),
+        });
+        println!("{}", report);
+    } else {
+        for e in errors.iter() {
+            println!("error: {}", e);
+        }
+        for w in warnings.iter() {
+            println!("warning: {}", w);
+        }
+        if valid {
+            println!("{} is valid", args.file.display());
+        }
+    }
+
+    match valid {
+        true => Ok(()),
+        false => Err(format!("{} failed verification", args.file.display()).into()),
+    }
+}
+
+/// Lists the chunks of a file, optionally only those whose data size is within
+/// `--min-size`/`--max-size`.
+pub fn print(args: &PrintArgs) -> Result<()> {
+    let bytes = fs::read(&args.file)?;
+    let mut png = Png::try_from(bytes.as_slice())?;
+    png.retain_chunks(|chunk| {
+        args.min_size.is_none_or(|min| chunk.length() >= min)
+            && args.max_size.is_none_or(|max| chunk.length() <= max)
+    });
+
+    for chunk in png.chunks() {
+        println!("{}\nLength : {}\n", chunk, chunk.length());
+    }
+    Ok(())
+}
+
+/// Adds a chunk holding the message before IEND, writing to `output` if given and back to the
+/// input file otherwise.
+pub fn encode(args: &EncodeArgs) -> Result<()> {
+    let mut png = Png::from_file(&args.file)?;
+    png.append_chunk(Chunk::from_strings(&args.chunk_type, &args.message)?);
+    fs::write(args.output.as_ref().unwrap_or(&args.file), png.as_bytes())?;
+    Ok(())
+}
+
+/// Prints the message in the first chunk of the given type.
+pub fn decode(args: &DecodeArgs) -> Result<()> {
+    let bytes = fs::read(&args.file)?;
+    let png = Png::try_from(bytes.as_slice())?;
+    let Some(chunk_type) = args.chunk_type.as_deref() else {
+        return search_text(&png, &args.file);
+    };
+    let matches = chunk_type_matcher(chunk_type, args.ignore_flags)?;
+
+    match png.chunks().iter().find(|chunk| matches(chunk)) {
+        Some(chunk) => {
+            println!("{}", chunk.data_as_string()?);
+            Ok(())
+        }
+        None => Err(format!("No {} chunk in {}", chunk_type, args.file.display()).into()),
+    }
+}
+
+/// Prints the type and text of every ancillary chunk whose data is readable UTF-8.
+fn search_text(png: &Png, file: &Path) -> Result<()> {
+    let readable =
+        |text: &str| !text.is_empty() && text.chars().all(|c| !c.is_control() || c.is_whitespace());
+    let found: Vec<(&Chunk, &str)> = png
+        .chunks()
+        .iter()
+        .filter(|chunk| !chunk.chunk_type().is_critical())
+        .filter_map(|chunk| Some((chunk, chunk.data_as_str().ok()?)))
+        .filter(|(_, text)| readable(text))
+        .collect();
+    if found.is_empty() {
+        return Err(format!("No readable text chunks in {}", file.display()).into());
+    }
+    for (chunk, text) in found {
+        println!("{}: {}", chunk.chunk_type(), text);
+    }
+    Ok(())
+}
+
+/// Removes the first chunk of the given type and writes the file back.
+pub fn remove(args: &RemoveArgs) -> Result<()> {
+    let bytes = fs::read(&args.file)?;
+    let mut png = Png::try_from(bytes.as_slice())?;
+    let matches = chunk_type_matcher(&args.chunk_type, args.ignore_flags)?;
+
+    let index = png.chunks().iter().position(&matches);
+    match index.and_then(|index| png.take_chunk(index)) {
+        Some(chunk) => {
+            fs::write(&args.file, png.as_bytes())?;
+            println!("Removed {}", chunk.chunk_type());
+            Ok(())
+        }
+        None => Err(format!("No {} chunk in {}", args.chunk_type, args.file.display()).into()),
+    }
+}
+
+/// Adds the first chunk of the given type in `src` to `dst`, before its IEND.
+pub fn copy(args: &CopyArgs) -> Result<()> {
+    let chunk_type = ChunkType::from_str(&args.chunk_type)?;
+    let src = Png::from_file(&args.src)?;
+    let mut dst = Png::from_file(&args.dst)?;
+
+    match src
+        .chunks()
+        .iter()
+        .find(|chunk| chunk.chunk_type() == &chunk_type)
+    {
+        Some(chunk) => {
+            dst.insert_before_iend(chunk.clone());
+            fs::write(&args.dst, dst.as_bytes())?;
+            println!("Copied {} to {}", chunk_type, args.dst.display());
+            Ok(())
+        }
+        None => Err(format!("No {} chunk in {}", args.chunk_type, args.src.display()).into()),
+    }
+}
+
+/// Prints the summary from `Png::statistics`.
+pub fn stats(args: &StatsArgs) -> Result<()> {
+    let png = Png::from_file(&args.file)?;
+    print!("{}", png.statistics());
+    Ok(())
+}
+
+/// Breaks a chunk type down into the properties its letter cases encode.
+pub fn chunktype(args: &ChunkTypeArgs) -> Result<()> {
+    let chunk_type = ChunkType::from_str(&args.chunk_type)?;
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+
+    println!("Type : {}", chunk_type);
+    println!("Valid : {}", yes_no(chunk_type.is_valid()));
+    println!("Critical : {}", yes_no(chunk_type.is_critical()));
+    println!("Public : {}", yes_no(chunk_type.is_public()));
+    println!(
+        "Reserved bit : {}",
+        match chunk_type.is_reserved_bit_valid() {
+            true => "valid",
+            false => "invalid",
+        }
+    );
+    println!("Safe to copy : {}", yes_no(chunk_type.is_safe_to_copy()));
+    if let Some(description) = chunk_type.description() {
+        println!("Description : {}", description);
+    }
+    Ok(())
+}
+
+/// Exact chunk type comparison by default, or by letters only with `--ignore-flags` so that
+/// `rust` finds `RuSt`.
+fn chunk_type_matcher(chunk_type: &str, ignore_flags: bool) -> Result<impl Fn(&Chunk) -> bool> {
+    let chunk_type = ChunkType::from_str(chunk_type)?;
+    Ok(move |chunk: &Chunk| match ignore_flags {
+        true => chunk.chunk_type().same_base(&chunk_type),
+        false => chunk.chunk_type() == &chunk_type,
+    })
+}
@@ -0,0 +1,97 @@
+use std::fmt::Display;
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use crate::Error;
+
+/// Default cap on how large decompressed chunk data may grow, guards against zip bombs in
+/// untrusted files.
+pub const DEFAULT_DECOMPRESSION_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Zlib-inflates `data`, failing once the output grows past `limit` bytes instead of
+/// allocating whatever the stream expands to.
+pub fn inflate(data: &[u8], limit: usize) -> Result<Vec<u8>, Error> {
+    let mut decoded = Vec::new();
+    // Read one byte past the limit so hitting it exactly is still allowed
+    ZlibDecoder::new(data)
+        .take((limit as u64).saturating_add(1))
+        .read_to_end(&mut decoded)?;
+    if decoded.len() > limit {
+        return Err(Box::new(CompressionError::LimitExceeded(limit)));
+    }
+    Ok(decoded)
+}
+
+/// Zlib-deflates `data` with the default compression level.
+pub fn deflate(data: &[u8]) -> Vec<u8> {
+    deflate_with_level(data, Compression::default().level())
+}
+
+/// Zlib-deflates `data` at `level`, from 0 (store only) to 9 (smallest).
+pub fn deflate_with_level(data: &[u8], level: u32) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(level));
+    // Writing into a Vec can't fail
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[derive(Debug)]
+enum CompressionError {
+    LimitExceeded(usize),
+}
+
+impl std::error::Error for CompressionError {}
+
+impl Display for CompressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressionError::LimitExceeded(limit) => {
+                write!(f, "Decompressed data exceeds the limit of {} bytes!", limit)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflate_round_trip() {
+        let data = b"This is where your secret message will be!".repeat(10);
+        let compressed = deflate(&data);
+        assert!(compressed.len() < data.len());
+        assert_eq!(
+            inflate(&compressed, DEFAULT_DECOMPRESSION_LIMIT).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_inflate_exactly_at_limit() {
+        let data = vec![0; 1024];
+        assert_eq!(inflate(&deflate(&data), 1024).unwrap(), data);
+    }
+
+    #[test]
+    fn test_inflate_unlimited() {
+        let data = vec![7; 1024];
+        assert_eq!(inflate(&deflate(&data), usize::MAX).unwrap(), data);
+    }
+
+    #[test]
+    fn test_inflate_over_limit() {
+        // A megabyte of zeros squeezes into about a kilobyte
+        let bomb = deflate(&vec![0; 1024 * 1024]);
+        assert!(bomb.len() < 2048);
+        assert!(inflate(&bomb, 64 * 1024).is_err());
+    }
+
+    #[test]
+    fn test_inflate_garbage() {
+        assert!(inflate(&[1, 2, 3, 4], DEFAULT_DECOMPRESSION_LIMIT).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt::Display;
+
+use crate::Error;
+
+const NONE: u8 = 0;
+const SUB: u8 = 1;
+const UP: u8 = 2;
+const AVERAGE: u8 = 3;
+const PAETH: u8 = 4;
+
+/// Reverses the per scanline filtering of decompressed (non interlaced) image data.
+/// `data` holds `height` scanlines of `stride` bytes, each prefixed with its filter type, and
+/// `bpp` is the number of bytes per complete pixel, rounded up to 1 for sub-byte depths.
+pub fn unfilter(data: &[u8], stride: usize, height: usize, bpp: usize) -> Result<Vec<u8>, Error> {
+    let (size, filtered_size) = image_sizes(stride, height)?;
+    if data.len() != filtered_size {
+        return Err(Box::new(FilterError::LengthError(
+            filtered_size,
+            data.len(),
+        )));
+    }
+
+    let mut pixels = vec![0; size];
+    let mut previous = vec![0; stride];
+    for (line, out) in data.chunks(stride + 1).zip(pixels.chunks_mut(stride)) {
+        let (filter_type, line) = (line[0], &line[1..]);
+        for i in 0..stride {
+            let a = if i >= bpp { out[i - bpp] } else { 0 };
+            let b = previous[i];
+            let c = if i >= bpp { previous[i - bpp] } else { 0 };
+            let predictor = match filter_type {
+                NONE => 0,
+                SUB => a,
+                UP => b,
+                AVERAGE => ((a as u16 + b as u16) / 2) as u8,
+                PAETH => paeth(a, b, c),
+                _ => return Err(Box::new(FilterError::UnknownFilterType(filter_type))),
+            };
+            out[i] = line[i].wrapping_add(predictor);
+        }
+        previous.copy_from_slice(out);
+    }
+    Ok(pixels)
+}
+
+/// Filters `height` scanlines of `stride` bytes for compression, the reverse of [`unfilter`].
+/// Every line uses the Sub filter, which is cheap and already helps on smooth images.
+pub fn filter(pixels: &[u8], stride: usize, height: usize, bpp: usize) -> Result<Vec<u8>, Error> {
+    let (size, filtered_size) = image_sizes(stride, height)?;
+    if pixels.len() != size {
+        return Err(Box::new(FilterError::LengthError(size, pixels.len())));
+    }
+
+    let mut data = Vec::with_capacity(filtered_size);
+    for line in pixels.chunks(stride) {
+        data.push(SUB);
+        for i in 0..stride {
+            let a = if i >= bpp { line[i - bpp] } else { 0 };
+            data.push(line[i].wrapping_sub(a));
+        }
+    }
+    Ok(data)
+}
+
+/// Bytes of `height` scanlines of `stride` bytes without and with their filter type bytes.
+/// Dimensions straight from an IHDR can be big enough to overflow these.
+fn image_sizes(stride: usize, height: usize) -> Result<(usize, usize), Error> {
+    let size = stride.checked_mul(height);
+    let filtered_size = stride
+        .checked_add(1)
+        .and_then(|line| line.checked_mul(height));
+    match (size, filtered_size) {
+        (Some(size), Some(filtered_size)) => Ok((size, filtered_size)),
+        _ => Err(Box::new(FilterError::TooLargeError(stride, height))),
+    }
+}
+
+/// Picks whichever of left, above and upper left is closest to `a + b - c`.
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let pa = (p - a as i16).abs();
+    let pb = (p - b as i16).abs();
+    let pc = (p - c as i16).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+#[derive(Debug)]
+enum FilterError {
+    LengthError(usize, usize),
+    UnknownFilterType(u8),
+    /// Stride and height whose image size doesn't fit in memory.
+    TooLargeError(usize, usize),
+}
+
+impl std::error::Error for FilterError {}
+
+impl Display for FilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilterError::LengthError(expected, got) => write!(
+                f,
+                "Image data Length Error! Expected {} bytes, Got {} bytes",
+                expected, got
+            ),
+            FilterError::UnknownFilterType(filter_type) => {
+                write!(f, "Unknown filter type {}", filter_type)
+            }
+            FilterError::TooLargeError(stride, height) => write!(
+                f,
+                "Image too large! {} scanlines of {} bytes overflow",
+                height, stride
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfilter_none_and_up() {
+        let data = [NONE, 1, 2, UP, 1, 1];
+        assert_eq!(unfilter(&data, 2, 2, 1).unwrap(), vec![1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_unfilter_average() {
+        // Only the left neighbour contributes on the first line
+        let data = [AVERAGE, 10, 15, 20];
+        assert_eq!(unfilter(&data, 3, 1, 1).unwrap(), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_unfilter_wraps_around() {
+        let data = [SUB, 200, 100];
+        assert_eq!(unfilter(&data, 2, 1, 1).unwrap(), vec![200, 44]);
+    }
+
+    #[test]
+    fn test_unfilter_invalid() {
+        assert!(unfilter(&[5, 0, 0], 2, 1, 1).is_err());
+        assert!(unfilter(&[NONE, 0], 2, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_image_size_overflow() {
+        let error = unfilter(&[], usize::MAX / 2, 3, 1).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<FilterError>(),
+            Some(FilterError::TooLargeError(_, 3))
+        ));
+        assert!(unfilter(&[], usize::MAX, 1, 1).is_err());
+        assert!(filter(&[], usize::MAX / 2, 3, 1).is_err());
+    }
+
+    #[test]
+    fn test_filter_round_trip() {
+        let pixels: Vec<u8> = (0..24).map(|i| (i * 37) as u8).collect();
+        let data = filter(&pixels, 8, 3, 4).unwrap();
+        assert_eq!(data.len(), 27);
+        assert_eq!(data[0], SUB);
+        assert_eq!(unfilter(&data, 8, 3, 4).unwrap(), pixels);
+
+        assert!(filter(&pixels, 8, 4, 4).is_err());
+    }
+
+    #[test]
+    fn test_paeth() {
+        assert_eq!(paeth(15, 40, 10), 40);
+        assert_eq!(paeth(0, 7, 0), 7);
+        assert_eq!(paeth(10, 10, 10), 10);
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::compression::{self, DEFAULT_DECOMPRESSION_LIMIT};
+use crate::Error;
+
+/// Parsed contents of the iCCP chunk, an embedded ICC colour profile.
+#[derive(PartialEq, Eq, Debug)]
+pub struct IccProfile {
+    name: String,
+    profile: Vec<u8>,
+}
+
+impl IccProfile {
+    /// The only compression method the spec defines, zlib.
+    pub const COMPRESSION_DEFLATE: u8 = 0;
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The decompressed ICC profile.
+    pub fn profile(&self) -> &[u8] {
+        &self.profile
+    }
+
+    /// Decodes an iCCP chunk, refusing to decompress more than `limit` bytes of profile.
+    pub fn from_chunk_with_limit(chunk: &Chunk, limit: usize) -> Result<IccProfile, Error> {
+        if !chunk.is_type("iCCP") {
+            return Err(Box::new(IccProfileError::WrongChunkType));
+        }
+        let data = chunk.data();
+        let Some(ind) = data.iter().position(|&b| b == 0) else {
+            return Err(Box::new(IccProfileError::MissingNullSeparator));
+        };
+        let (name, rest) = (&data[..ind], &data[ind + 1..]);
+        let Some((&method, compressed)) = rest.split_first() else {
+            return Err(Box::new(IccProfileError::MissingCompressionMethod));
+        };
+        if method != IccProfile::COMPRESSION_DEFLATE {
+            return Err(Box::new(IccProfileError::UnknownCompressionMethod(method)));
+        }
+
+        Ok(IccProfile {
+            // Latin-1, every byte is its own code point
+            name: name.iter().map(|&b| b as char).collect(),
+            profile: compression::inflate(compressed, limit)?,
+        })
+    }
+}
+
+/// Decompresses with the [`DEFAULT_DECOMPRESSION_LIMIT`].
+impl TryFrom<&Chunk> for IccProfile {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        IccProfile::from_chunk_with_limit(chunk, DEFAULT_DECOMPRESSION_LIMIT)
+    }
+}
+
+#[derive(Debug)]
+enum IccProfileError {
+    WrongChunkType,
+    MissingNullSeparator,
+    MissingCompressionMethod,
+    UnknownCompressionMethod(u8),
+}
+
+impl std::error::Error for IccProfileError {}
+
+impl Display for IccProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IccProfileError::WrongChunkType => write!(f, "Chunk is not an iCCP chunk!"),
+            IccProfileError::MissingNullSeparator => {
+                write!(f, "iCCP profile name is not null terminated!")
+            }
+            IccProfileError::MissingCompressionMethod => {
+                write!(f, "iCCP chunk has no compression method!")
+            }
+            IccProfileError::UnknownCompressionMethod(method) => {
+                write!(f, "Unknown iCCP compression method {}", method)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn iccp_chunk(name: &str, profile: &[u8]) -> Chunk {
+        let mut data = name.as_bytes().to_vec();
+        data.extend_from_slice(&[0, IccProfile::COMPRESSION_DEFLATE]);
+        data.extend_from_slice(&compression::deflate(profile));
+        Chunk::new(ChunkType::from_str("iCCP").unwrap(), data)
+    }
+
+    #[test]
+    fn test_valid_iccp() {
+        let chunk = iccp_chunk("sRGB IEC61966-2.1", b"profile bytes");
+        let iccp = IccProfile::try_from(&chunk).unwrap();
+        assert_eq!(iccp.name(), "sRGB IEC61966-2.1");
+        assert_eq!(iccp.profile(), b"profile bytes");
+    }
+
+    #[test]
+    fn test_iccp_over_limit() {
+        // A megabyte of zeros squeezes into about a kilobyte
+        let chunk = iccp_chunk("bomb", &vec![0; 1024 * 1024]);
+        assert!(chunk.length() < 2048);
+        assert!(IccProfile::from_chunk_with_limit(&chunk, 64 * 1024).is_err());
+        assert!(IccProfile::from_chunk_with_limit(&chunk, 1024 * 1024).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_iccp() {
+        let chunk = Chunk::new(
+            ChunkType::from_str("iCCP").unwrap(),
+            b"no separator".to_vec(),
+        );
+        assert!(IccProfile::try_from(&chunk).is_err());
+
+        // Compression method 1 isn't defined
+        let chunk = Chunk::new(ChunkType::from_str("iCCP").unwrap(), b"name\0\x01".to_vec());
+        assert!(IccProfile::try_from(&chunk).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::Error;
+
+/// Colour types allowed by the PNG spec, the discriminant is the byte stored in IHDR.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ColorType {
+    Grayscale = 0,
+    Rgb = 2,
+    Indexed = 3,
+    GrayscaleAlpha = 4,
+    Rgba = 6,
+}
+
+impl ColorType {
+    /// Samples per pixel, an indexed pixel is a single palette index.
+    pub fn channels(&self) -> u8 {
+        match self {
+            ColorType::Grayscale | ColorType::Indexed => 1,
+            ColorType::GrayscaleAlpha => 2,
+            ColorType::Rgb => 3,
+            ColorType::Rgba => 4,
+        }
+    }
+
+    /// Bit depths the spec allows for this colour type.
+    pub fn allowed_bit_depths(&self) -> &'static [u8] {
+        match self {
+            ColorType::Grayscale => &[1, 2, 4, 8, 16],
+            ColorType::Indexed => &[1, 2, 4, 8],
+            ColorType::Rgb | ColorType::GrayscaleAlpha | ColorType::Rgba => &[8, 16],
+        }
+    }
+}
+
+impl TryFrom<u8> for ColorType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ColorType::Grayscale),
+            2 => Ok(ColorType::Rgb),
+            3 => Ok(ColorType::Indexed),
+            4 => Ok(ColorType::GrayscaleAlpha),
+            6 => Ok(ColorType::Rgba),
+            _ => Err(Box::new(IhdrError::InvalidColorType(value))),
+        }
+    }
+}
+
+impl Display for ColorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorType::Grayscale => write!(f, "Grayscale"),
+            ColorType::Rgb => write!(f, "RGB"),
+            ColorType::Indexed => write!(f, "Indexed"),
+            ColorType::GrayscaleAlpha => write!(f, "Grayscale+Alpha"),
+            ColorType::Rgba => write!(f, "RGBA"),
+        }
+    }
+}
+
+/// Parsed contents of the IHDR chunk.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Ihdr {
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: ColorType,
+    interlace_method: u8,
+}
+
+impl Ihdr {
+    pub const LENGTH: usize = 13;
+    /// Width and height are limited to 2^31 - 1 by the spec.
+    const MAX_DIMENSION: u32 = i32::MAX as u32;
+
+    /// A non interlaced header, checking the dimensions and that `bit_depth` is allowed for
+    /// `color_type`.
+    pub fn new(
+        width: u32,
+        height: u32,
+        bit_depth: u8,
+        color_type: ColorType,
+    ) -> Result<Ihdr, Error> {
+        if width == 0 || height == 0 || width > Ihdr::MAX_DIMENSION || height > Ihdr::MAX_DIMENSION
+        {
+            return Err(Box::new(IhdrError::InvalidDimensions(width, height)));
+        }
+        if !color_type.allowed_bit_depths().contains(&bit_depth) {
+            return Err(Box::new(IhdrError::InvalidBitDepth(bit_depth, color_type)));
+        }
+        Ok(Ihdr {
+            width,
+            height,
+            bit_depth,
+            color_type,
+            interlace_method: 0,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth
+    }
+    pub fn color_type(&self) -> ColorType {
+        self.color_type
+    }
+    pub fn interlace_method(&self) -> u8 {
+        self.interlace_method
+    }
+    /// Bytes in one unfiltered scanline, sub-byte depths are packed and rounded up.
+    pub fn stride(&self) -> usize {
+        (self.width as usize * self.bits_per_pixel()).div_ceil(8)
+    }
+    pub fn bits_per_pixel(&self) -> usize {
+        self.color_type.channels() as usize * self.bit_depth as usize
+    }
+
+    pub fn to_chunk(&self) -> Chunk {
+        let mut data = Vec::with_capacity(Ihdr::LENGTH);
+        data.extend_from_slice(&self.width.to_be_bytes());
+        data.extend_from_slice(&self.height.to_be_bytes());
+        // Compression and filter method are always 0
+        data.extend_from_slice(&[
+            self.bit_depth,
+            self.color_type as u8,
+            0,
+            0,
+            self.interlace_method,
+        ]);
+        Chunk::new(ChunkType::IHDR, data)
+    }
+}
+
+impl TryFrom<&Chunk> for Ihdr {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if !chunk.is_type("IHDR") {
+            return Err(Box::new(IhdrError::WrongChunkType));
+        }
+        let data = chunk.data();
+        if data.len() != Ihdr::LENGTH {
+            return Err(Box::new(IhdrError::LengthError(data.len())));
+        }
+
+        let width = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+        let height = u32::from_be_bytes([data[4], data[5], data[6], data[7]]);
+        let color_type = ColorType::try_from(data[9])?;
+        let ihdr = Ihdr::new(width, height, data[8], color_type)?;
+
+        // Compression and filter method 0 are the only ones defined
+        if data[10] != 0 || data[11] != 0 {
+            return Err(Box::new(IhdrError::UnknownMethod));
+        }
+        let interlace_method = data[12];
+        if interlace_method > 1 {
+            return Err(Box::new(IhdrError::UnknownMethod));
+        }
+
+        Ok(Ihdr {
+            interlace_method,
+            ..ihdr
+        })
+    }
+}
+
+impl Display for Ihdr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}x{}, {}-bit {}",
+            self.width, self.height, self.bit_depth, self.color_type
+        )?;
+        if self.interlace_method == 1 {
+            write!(f, ", interlaced")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum IhdrError {
+    WrongChunkType,
+    LengthError(usize),
+    InvalidDimensions(u32, u32),
+    InvalidColorType(u8),
+    InvalidBitDepth(u8, ColorType),
+    UnknownMethod,
+}
+
+impl std::error::Error for IhdrError {}
+
+impl Display for IhdrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IhdrError::WrongChunkType => write!(f, "Chunk is not an IHDR chunk!"),
+            IhdrError::LengthError(got) => {
+                write!(
+                    f,
+                    "IHDR Length Error! Expected {} bytes, Got {} bytes",
+                    Ihdr::LENGTH,
+                    got
+                )
+            }
+            IhdrError::InvalidDimensions(width, height) => {
+                write!(f, "Invalid image dimensions {}x{}", width, height)
+            }
+            IhdrError::InvalidColorType(color_type) => {
+                write!(f, "Invalid colour type {}", color_type)
+            }
+            IhdrError::InvalidBitDepth(bit_depth, color_type) => {
+                write!(
+                    f,
+                    "Bit depth {} is not allowed for {}",
+                    bit_depth, color_type
+                )
+            }
+            IhdrError::UnknownMethod => {
+                write!(f, "Unknown compression, filter or interlace method!")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn ihdr_chunk(data: Vec<u8>) -> Chunk {
+        Chunk::new(ChunkType::from_str("IHDR").unwrap(), data)
+    }
+
+    #[test]
+    fn test_valid_ihdr() {
+        let chunk = ihdr_chunk(vec![0, 0, 3, 32, 0, 0, 2, 88, 8, 6, 0, 0, 0]);
+        let ihdr = Ihdr::try_from(&chunk).unwrap();
+        assert_eq!(ihdr.width(), 800);
+        assert_eq!(ihdr.height(), 600);
+        assert_eq!(ihdr.bit_depth(), 8);
+        assert_eq!(ihdr.color_type(), ColorType::Rgba);
+        assert_eq!(ihdr.interlace_method(), 0);
+        assert_eq!(&ihdr.to_string(), "800x600, 8-bit RGBA");
+        assert_eq!(ihdr.bits_per_pixel(), 32);
+        assert_eq!(ihdr.stride(), 3200);
+    }
+
+    #[test]
+    fn test_ihdr_packed_stride() {
+        // 3 pixels wide, 1-bit grayscale
+        let chunk = ihdr_chunk(vec![0, 0, 0, 3, 0, 0, 0, 1, 1, 0, 0, 0, 0]);
+        let ihdr = Ihdr::try_from(&chunk).unwrap();
+        assert_eq!(ihdr.bits_per_pixel(), 1);
+        assert_eq!(ihdr.stride(), 1);
+    }
+
+    #[test]
+    fn test_ihdr_to_chunk_round_trip() {
+        let ihdr = Ihdr::new(800, 600, 8, ColorType::Rgba).unwrap();
+        let chunk = ihdr.to_chunk();
+        assert_eq!(chunk.data(), &[0, 0, 3, 32, 0, 0, 2, 88, 8, 6, 0, 0, 0]);
+        assert_eq!(Ihdr::try_from(&chunk).unwrap(), ihdr);
+
+        assert!(Ihdr::new(0, 600, 8, ColorType::Rgba).is_err());
+        assert!(Ihdr::new(800, 600, 4, ColorType::Rgba).is_err());
+    }
+
+    #[test]
+    fn test_invalid_ihdr_length() {
+        let chunk = ihdr_chunk(vec![0, 0, 3, 32]);
+        assert!(Ihdr::try_from(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_invalid_ihdr_zero_width() {
+        let chunk = ihdr_chunk(vec![0, 0, 0, 0, 0, 0, 2, 88, 8, 6, 0, 0, 0]);
+        assert!(Ihdr::try_from(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_invalid_ihdr_bit_depth() {
+        // RGBA doesn't allow a bit depth of 4
+        let chunk = ihdr_chunk(vec![0, 0, 3, 32, 0, 0, 2, 88, 4, 6, 0, 0, 0]);
+        assert!(Ihdr::try_from(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_ihdr_wrong_chunk_type() {
+        let chunk = Chunk::new(
+            ChunkType::from_str("RuSt").unwrap(),
+            vec![0, 0, 3, 32, 0, 0, 2, 88, 8, 6, 0, 0, 0],
+        );
+        assert!(Ihdr::try_from(&chunk).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+pub mod chrm;
+pub mod chunk;
+pub mod chunk_type;
+pub mod compression;
+pub mod filter;
+pub mod iccp;
+pub mod ihdr;
+pub mod offs;
+pub mod phys;
+pub mod png;
+pub mod reader;
+pub mod stats;
+pub mod text;
+pub mod time;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
@@ -1,12 +1,29 @@
 mod arg;
-mod chunk;
-mod chunk_type;
 mod commands;
-mod png;
 
-pub type Error = Box<dyn std::error::Error>;
-pub type Result<T> = std::result::Result<T, Error>;
+use std::process::ExitCode;
 
-fn main() -> Result<()>{
-    todo!()
+use arg::{Cli, Command};
+use clap::Parser;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Verify(args) => commands::verify(args),
+        Command::Print(args) => commands::print(args),
+        Command::Encode(args) => commands::encode(args),
+        Command::Decode(args) => commands::decode(args),
+        Command::Remove(args) => commands::remove(args),
+        Command::Copy(args) => commands::copy(args),
+        Command::Stats(args) => commands::stats(args),
+        Command::Chunktype(args) => commands::chunktype(args),
+    };
+    // Returning the error from main would print its Debug form, not the message
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
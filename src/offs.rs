@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::Error;
+
+/// Parsed contents of the oFFs chunk, the image position on a printed page or scanner bed.
+#[derive(PartialEq, Eq, Debug)]
+pub struct ImageOffset {
+    x: i32,
+    y: i32,
+    unit: u8,
+}
+
+impl ImageOffset {
+    pub const LENGTH: usize = 9;
+    pub const UNIT_PIXEL: u8 = 0;
+    pub const UNIT_MICROMETRE: u8 = 1;
+
+    pub fn new(x: i32, y: i32, unit: u8) -> Result<ImageOffset, Error> {
+        if unit > ImageOffset::UNIT_MICROMETRE {
+            return Err(Box::new(ImageOffsetError::InvalidUnit(unit)));
+        }
+        Ok(ImageOffset { x, y, unit })
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+    pub fn unit(&self) -> u8 {
+        self.unit
+    }
+
+    pub fn to_chunk(&self) -> Chunk {
+        let data: Vec<u8> = self
+            .x
+            .to_be_bytes()
+            .iter()
+            .chain(self.y.to_be_bytes().iter())
+            .chain([self.unit].iter())
+            .copied()
+            .collect();
+        Chunk::new(ChunkType::try_from(*b"oFFs").unwrap(), data)
+    }
+}
+
+impl TryFrom<&Chunk> for ImageOffset {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if !chunk.is_type("oFFs") {
+            return Err(Box::new(ImageOffsetError::WrongChunkType));
+        }
+        let data = chunk.data();
+        if data.len() != ImageOffset::LENGTH {
+            return Err(Box::new(ImageOffsetError::LengthError(data.len())));
+        }
+
+        ImageOffset::new(
+            i32::from_be_bytes([data[0], data[1], data[2], data[3]]),
+            i32::from_be_bytes([data[4], data[5], data[6], data[7]]),
+            data[8],
+        )
+    }
+}
+
+#[derive(Debug)]
+enum ImageOffsetError {
+    WrongChunkType,
+    LengthError(usize),
+    InvalidUnit(u8),
+}
+
+impl std::error::Error for ImageOffsetError {}
+
+impl Display for ImageOffsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageOffsetError::WrongChunkType => write!(f, "Chunk is not an oFFs chunk!"),
+            ImageOffsetError::LengthError(got) => {
+                write!(
+                    f,
+                    "oFFs Length Error! Expected {} bytes, Got {} bytes",
+                    ImageOffset::LENGTH,
+                    got
+                )
+            }
+            ImageOffsetError::InvalidUnit(unit) => write!(f, "Invalid oFFs unit {}", unit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_image_offset_round_trip() {
+        let offset = ImageOffset::new(-1200, 35000, ImageOffset::UNIT_MICROMETRE).unwrap();
+        let chunk = offset.to_chunk();
+        assert_eq!(chunk.length(), 9);
+        assert_eq!(ImageOffset::try_from(&chunk).unwrap(), offset);
+    }
+
+    #[test]
+    fn test_image_offset_negative_bytes() {
+        let chunk = Chunk::new(
+            ChunkType::from_str("oFFs").unwrap(),
+            vec![255, 255, 255, 255, 0, 0, 0, 2, 0],
+        );
+        let offset = ImageOffset::try_from(&chunk).unwrap();
+        assert_eq!(offset.x(), -1);
+        assert_eq!(offset.y(), 2);
+        assert_eq!(offset.unit(), ImageOffset::UNIT_PIXEL);
+    }
+
+    #[test]
+    fn test_invalid_image_offset() {
+        assert!(ImageOffset::new(0, 0, 2).is_err());
+
+        let chunk = Chunk::new(ChunkType::from_str("oFFs").unwrap(), vec![0; 8]);
+        assert!(ImageOffset::try_from(&chunk).is_err());
+    }
+}
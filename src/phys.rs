@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::Error;
+
+/// Parsed contents of the pHYs chunk, the intended pixel size or aspect ratio.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Phys {
+    pixels_per_unit_x: u32,
+    pixels_per_unit_y: u32,
+    unit: u8,
+}
+
+impl Phys {
+    pub const LENGTH: usize = 9;
+    /// Unit byte meaning the values only give the aspect ratio.
+    pub const UNIT_UNKNOWN: u8 = 0;
+    pub const UNIT_METRE: u8 = 1;
+
+    pub fn pixels_per_unit_x(&self) -> u32 {
+        self.pixels_per_unit_x
+    }
+    pub fn pixels_per_unit_y(&self) -> u32 {
+        self.pixels_per_unit_y
+    }
+    pub fn unit(&self) -> u8 {
+        self.unit
+    }
+}
+
+impl TryFrom<&Chunk> for Phys {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if !chunk.is_type("pHYs") {
+            return Err(Box::new(PhysError::WrongChunkType));
+        }
+        let data = chunk.data();
+        if data.len() != Phys::LENGTH {
+            return Err(Box::new(PhysError::LengthError(data.len())));
+        }
+
+        let unit = data[8];
+        if unit > Phys::UNIT_METRE {
+            return Err(Box::new(PhysError::InvalidUnit(unit)));
+        }
+        Ok(Phys {
+            pixels_per_unit_x: u32::from_be_bytes([data[0], data[1], data[2], data[3]]),
+            pixels_per_unit_y: u32::from_be_bytes([data[4], data[5], data[6], data[7]]),
+            unit,
+        })
+    }
+}
+
+impl Display for Phys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.unit {
+            Phys::UNIT_METRE => write!(
+                f,
+                "{}x{} pixels per metre",
+                self.pixels_per_unit_x, self.pixels_per_unit_y
+            ),
+            _ => write!(
+                f,
+                "aspect ratio {}:{}",
+                self.pixels_per_unit_x, self.pixels_per_unit_y
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum PhysError {
+    WrongChunkType,
+    LengthError(usize),
+    InvalidUnit(u8),
+}
+
+impl std::error::Error for PhysError {}
+
+impl Display for PhysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PhysError::WrongChunkType => write!(f, "Chunk is not a pHYs chunk!"),
+            PhysError::LengthError(got) => {
+                write!(
+                    f,
+                    "pHYs Length Error! Expected {} bytes, Got {} bytes",
+                    Phys::LENGTH,
+                    got
+                )
+            }
+            PhysError::InvalidUnit(unit) => write!(f, "Invalid pHYs unit {}", unit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn phys_chunk(data: Vec<u8>) -> Chunk {
+        Chunk::new(ChunkType::from_str("pHYs").unwrap(), data)
+    }
+
+    #[test]
+    fn test_valid_phys() {
+        let chunk = phys_chunk(vec![0, 0, 14, 195, 0, 0, 14, 195, 1]);
+        let phys = Phys::try_from(&chunk).unwrap();
+        assert_eq!(phys.pixels_per_unit_x(), 3779);
+        assert_eq!(phys.pixels_per_unit_y(), 3779);
+        assert_eq!(phys.unit(), Phys::UNIT_METRE);
+        assert_eq!(&phys.to_string(), "3779x3779 pixels per metre");
+    }
+
+    #[test]
+    fn test_invalid_phys_unit() {
+        let chunk = phys_chunk(vec![0, 0, 14, 195, 0, 0, 14, 195, 2]);
+        assert!(Phys::try_from(&chunk).is_err());
+    }
+}
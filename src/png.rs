@@ -1,47 +1,168 @@
-use std::io::{BufReader, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 
 use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::compression::{self, DEFAULT_DECOMPRESSION_LIMIT};
+use crate::filter;
+use crate::iccp::IccProfile;
+#[cfg(feature = "encode")]
+use crate::ihdr::ColorType;
+use crate::ihdr::Ihdr;
+use crate::reader::ChunkReader;
+use crate::stats::{ChunkTypeStats, PngStats};
+use crate::text::{TextChunk, TextKind};
 use crate::Error;
+#[derive(Clone)]
 pub struct Png {
+    signature: [u8; 8],
     chunks: Vec<Chunk>,
 }
 
 impl Png {
-    const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+    /// The 8-byte signature every PNG file starts with.
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+    /// Ancillary chunks describing colour management.
+    const COLOR_PROFILE_CHUNKS: [&'static str; 4] = ["iCCP", "sRGB", "gAMA", "cHRM"];
+    const XMP_KEYWORD: &'static str = "XML:com.adobe.xmp";
     pub fn new() -> Self {
-        Png { chunks: Vec::new() }
+        Png::from_chunks(Vec::new())
+    }
+    /// An empty Png with room for `capacity` chunks, for building large files without
+    /// reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Png::from_chunks(Vec::with_capacity(capacity))
     }
     pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
-        Png { chunks }
+        Png::with_signature(Png::STANDARD_HEADER, chunks)
+    }
+    /// For formats that reuse PNG framing behind their own magic bytes.
+    pub fn with_signature(signature: [u8; 8], chunks: Vec<Chunk>) -> Self {
+        Png { signature, chunks }
     }
 
-    fn append_chunk(&mut self, chunk: Chunk) {
-        self.chunks.push(chunk)
+    /// Adds the chunk as the last one before IEND, or at the end if there is no IEND yet.
+    /// Chunks after IEND are ignored by decoders so this never puts one there.
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.insert_before_iend(chunk)
+    }
+    /// Inserts the chunk right before IEND, or at the end if there is no IEND.
+    pub fn insert_before_iend(&mut self, chunk: Chunk) {
+        match self.chunks.iter().position(|chunk| chunk.is_type("IEND")) {
+            Some(ind) => self.chunks.insert(ind, chunk),
+            None => self.chunks.push(chunk),
+        }
+    }
+    /// Splices all `chunks` in right before IEND, in order, looking IEND up only once.
+    /// Fails without changing anything if there is no IEND.
+    pub fn insert_many_before_iend(&mut self, chunks: Vec<Chunk>) -> Result<(), Error> {
+        match self.chunks.iter().position(|chunk| chunk.is_type("IEND")) {
+            Some(ind) => {
+                self.chunks.splice(ind..ind, chunks);
+                Ok(())
+            }
+            None => Err(Box::new(PngError::MissingIend)),
+        }
     }
-    fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk, Error> {
+    /// Removes and returns the first chunk of type `chunk_type`.
+    pub fn remove_first_chunk_by_type(&mut self, chunk_type: &str) -> Result<Chunk, Error> {
         if let Some(ind) = self
             .chunks
             .iter()
-            .position(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .position(|chunk| chunk.is_type(chunk_type))
         {
             Ok(self.chunks.remove(ind))
         } else {
             Err(Box::new(PngError::UnknownChunkType))
         }
     }
-    fn header(&self) -> &[u8; 8] {
-        &Png::STANDARD_HEADER
+    /// Removes and returns the chunk at `index`, or `None` if it is out of bounds.
+    pub fn take_chunk(&mut self, index: usize) -> Option<Chunk> {
+        if index < self.chunks.len() {
+            Some(self.chunks.remove(index))
+        } else {
+            None
+        }
+    }
+    /// Splits the chunks into those before the first IEND, the IEND itself and anything after
+    /// it. Without an IEND every chunk is in the first slice.
+    pub fn split_at_iend(&self) -> (&[Chunk], Option<&Chunk>, &[Chunk]) {
+        match self.chunks.iter().position(|chunk| chunk.is_type("IEND")) {
+            Some(ind) => (
+                &self.chunks[..ind],
+                Some(&self.chunks[ind]),
+                &self.chunks[ind + 1..],
+            ),
+            None => (&self.chunks, None, &[]),
+        }
+    }
+    /// How many chunks follow the first IEND, which decoders ignore and so can hide data.
+    pub fn chunks_after_iend(&self) -> usize {
+        self.split_at_iend().2.len()
     }
-    fn chunks(&self) -> &[Chunk] {
+    /// The 8 bytes written before the chunks, the standard PNG signature unless built with
+    /// [`Png::with_signature`].
+    pub fn signature(&self) -> &[u8; 8] {
+        &self.signature
+    }
+    pub fn chunks(&self) -> &[Chunk] {
         &self.chunks
     }
-    fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+    /// Consumes the file, for rebuilding another one from its chunks without cloning them.
+    pub fn into_chunks(self) -> Vec<Chunk> {
+        self.chunks
+    }
+    /// For editing chunks in place, e.g. rewriting every custom chunk's data.
+    pub fn chunks_mut(&mut self) -> &mut [Chunk] {
+        &mut self.chunks
+    }
+    /// The chunk at `index`, for when [`Png`]'s `Index` impl would panic.
+    pub fn get(&self, index: usize) -> Option<&Chunk> {
+        self.chunks.get(index)
+    }
+    /// Where the chunk at `index` starts in the serialized file, i.e. the offset of its
+    /// length field.
+    pub fn byte_offset_of(&self, index: usize) -> Option<usize> {
+        if index >= self.chunks.len() {
+            return None;
+        }
+        let size: usize = self.chunks[..index]
+            .iter()
+            .map(|chunk| Chunk::serialized_size(chunk.length() as usize))
+            .sum();
+        Some(self.signature.len() + size)
+    }
+    /// In a well formed PNG this is IHDR.
+    pub fn first_chunk(&self) -> Option<&Chunk> {
+        self.chunks.first()
+    }
+    /// In a well formed PNG this is IEND.
+    pub fn last_chunk(&self) -> Option<&Chunk> {
+        self.chunks.last()
+    }
+    /// The first chunk of type `chunk_type`, `None` if there is none or it isn't a valid type.
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        let chunk_type = ChunkType::from_str(chunk_type).ok()?;
+        self.chunks
+            .iter()
+            .find(|chunk| *chunk.chunk_type() == chunk_type)
+    }
+    /// Every chunk of type `chunk_type` in file order, ancillary types like tEXt may repeat.
+    pub fn chunks_by_type(&self, chunk_type: &str) -> Vec<&Chunk> {
+        let Ok(chunk_type) = ChunkType::from_str(chunk_type) else {
+            return Vec::new();
+        };
         self.chunks
             .iter()
-            .find(|&chunk| chunk.chunk_type().to_string() == chunk_type)
+            .filter(|chunk| *chunk.chunk_type() == chunk_type)
+            .collect()
     }
-    fn as_bytes(&self) -> Vec<u8> {
-        self.header()
+    /// The signature followed by every chunk, i.e. the file contents.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.signature()
             .iter()
             .copied()
             .chain(
@@ -51,34 +172,662 @@ impl Png {
             )
             .collect::<Vec<_>>()
     }
-}
 
-impl TryFrom<&[u8]> for Png {
-    type Error = Error;
+    /// The data of all IDAT chunks concatenated, which together form one zlib stream.
+    pub fn idat_data(&self) -> Vec<u8> {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.is_type("IDAT"))
+            .flat_map(|chunk| chunk.data().iter().copied())
+            .collect()
+    }
 
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        // Header
-        let header = &value[..Png::STANDARD_HEADER.len()];
-        if Png::STANDARD_HEADER != header {
-            return Err(Box::new(PngError::InvalidHeader));
+    /// Inflates the IDAT stream into the filtered scanlines, each prefixed by its filter type
+    /// byte. Capped at [`DEFAULT_DECOMPRESSION_LIMIT`].
+    pub fn idat_decompressed(&self) -> Result<Vec<u8>, Error> {
+        self.idat_decompressed_with_limit(DEFAULT_DECOMPRESSION_LIMIT)
+    }
+
+    /// Like [`Png::idat_decompressed`] but failing once the output grows past `limit` bytes.
+    pub fn idat_decompressed_with_limit(&self, limit: usize) -> Result<Vec<u8>, Error> {
+        compression::inflate(&self.idat_data(), limit)
+    }
+
+    /// The embedded ICC profile, `None` without an iCCP chunk. Capped at
+    /// [`DEFAULT_DECOMPRESSION_LIMIT`].
+    pub fn icc_profile(&self) -> Result<Option<IccProfile>, Error> {
+        self.icc_profile_with_limit(DEFAULT_DECOMPRESSION_LIMIT)
+    }
+
+    /// Like [`Png::icc_profile`] but failing once the profile grows past `limit` bytes.
+    pub fn icc_profile_with_limit(&self, limit: usize) -> Result<Option<IccProfile>, Error> {
+        self.chunk_by_type("iCCP")
+            .map(|chunk| IccProfile::from_chunk_with_limit(chunk, limit))
+            .transpose()
+    }
+
+    /// Decodes the image data into scanlines of unfiltered pixel bytes, laid out as IHDR
+    /// describes (sub-byte depths stay packed, 16-bit samples are big-endian). Interlaced
+    /// images aren't supported.
+    pub fn to_raw_pixels(&self) -> Result<Vec<u8>, Error> {
+        let ihdr = Ihdr::try_from(
+            self.chunk_by_type("IHDR")
+                .ok_or(PngError::UnknownChunkType)?,
+        )?;
+        if ihdr.interlace_method() != 0 {
+            return Err(Box::new(PngError::Interlaced));
+        }
+        filter::unfilter(
+            &self.idat_decompressed()?,
+            ihdr.stride(),
+            ihdr.height() as usize,
+            ihdr.bits_per_pixel().div_ceil(8),
+        )
+    }
+
+    /// Encodes `width` x `height` 8-bit RGBA pixels, 4 bytes per pixel row by row, into a
+    /// minimal non interlaced PNG of IHDR, a single IDAT and IEND.
+    #[cfg(feature = "encode")]
+    pub fn from_rgba(width: u32, height: u32, pixels: &[u8]) -> Result<Png, Error> {
+        let ihdr = Ihdr::new(width, height, 8, ColorType::Rgba)?;
+        let filtered = filter::filter(
+            pixels,
+            ihdr.stride(),
+            height as usize,
+            ihdr.bits_per_pixel() / 8,
+        )?;
+        Ok(Png::from_chunks(vec![
+            ihdr.to_chunk(),
+            Chunk::new(ChunkType::IDAT, compression::deflate(&filtered)),
+            Chunk::iend(),
+        ]))
+    }
+
+    /// Removes later chunks with the same type and data as an earlier one, keeping the first
+    /// occurrence, and returns how many were removed. IDAT chunks are left alone since
+    /// repeated pieces of the image stream are legitimate.
+    pub fn dedup_identical_chunks(&mut self) -> usize {
+        let before = self.chunks.len();
+        let mut kept: Vec<Chunk> = Vec::with_capacity(before);
+        for chunk in self.chunks.drain(..) {
+            let duplicate = !chunk.is_type("IDAT")
+                && kept.iter().any(|other| {
+                    other.chunk_type() == chunk.chunk_type() && other.data() == chunk.data()
+                });
+            if !duplicate {
+                kept.push(chunk);
+            }
+        }
+        self.chunks = kept;
+        before - self.chunks.len()
+    }
+
+    /// The serialized file as a `data:image/png;base64,...` URL for embedding in HTML or CSS.
+    pub fn to_base64_data_url(&self) -> String {
+        format!("data:image/png;base64,{}", STANDARD.encode(self.as_bytes()))
+    }
+
+    /// Keeps only the chunks for which `f` returns true.
+    pub fn retain_chunks<F: FnMut(&Chunk) -> bool>(&mut self, f: F) {
+        self.chunks.retain(f)
+    }
+
+    /// Removes every colour management chunk (iCCP, sRGB, gAMA, cHRM) so decoders fall back
+    /// to their default rendering. Returns how many chunks were removed.
+    pub fn strip_color_profile(&mut self) -> usize {
+        let before = self.chunks.len();
+        self.chunks.retain(|chunk| {
+            !Png::COLOR_PROFILE_CHUNKS
+                .iter()
+                .any(|chunk_type| chunk.is_type(chunk_type))
+        });
+        before - self.chunks.len()
+    }
+
+    /// Gives every chunk of type `from` the type `to`, their CRCs are recomputed for the new
+    /// type. Returns how many chunks were renamed.
+    pub fn rename_chunk_type(&mut self, from: &str, to: &str) -> Result<usize, Error> {
+        let to = ChunkType::from_str(to)?;
+        let mut renamed = 0;
+        for chunk in self.chunks.iter_mut() {
+            if chunk.is_type(from) {
+                *chunk = Chunk::new(to, chunk.data().to_vec());
+                renamed += 1;
+            }
+        }
+        Ok(renamed)
+    }
+
+    /// Copies every ancillary, safe-to-copy chunk of `other` that this PNG doesn't already have
+    /// (same type and data) in front of IEND. Critical and unsafe-to-copy chunks are left out
+    /// since they may depend on the image data of `other`.
+    pub fn merge_ancillary_from(&mut self, other: &Png) {
+        for chunk in other.chunks.iter().filter(|chunk| {
+            !chunk.chunk_type().is_critical() && chunk.chunk_type().is_safe_to_copy()
+        }) {
+            let already_present = self
+                .chunks
+                .iter()
+                .any(|own| own.chunk_type() == chunk.chunk_type() && own.data() == chunk.data());
+            if !already_present {
+                self.insert_before_iend(chunk.clone());
+            }
+        }
+    }
+
+    /// SHA-256 of the serialized file, fed chunk by chunk instead of hashing `as_bytes()` so
+    /// the whole file is never buffered. Useful as a dedup or cache key.
+    #[cfg(feature = "sha2")]
+    pub fn content_hash(&self) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.signature());
+        for chunk in self.chunks.iter() {
+            hasher.update(chunk.length().to_be_bytes());
+            hasher.update(chunk.chunk_type().bytes());
+            hasher.update(chunk.data());
+            hasher.update(chunk.crc().to_be_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    /// Type and CRC of every chunk in file order, comparing two of these shows which chunks
+    /// changed without comparing the data itself.
+    pub fn data_checksums(&self) -> Vec<(String, u32)> {
+        self.chunks
+            .iter()
+            .map(|chunk| (chunk.chunk_type().to_string(), chunk.crc()))
+            .collect()
+    }
+
+    /// Whether both files have the same signature and chunks, comparing chunk type and data
+    /// but not the CRCs stored when they were parsed.
+    pub fn equal_ignoring_crc(&self, other: &Png) -> bool {
+        // Chunk's PartialEq already leaves the stored CRC out
+        self.signature == other.signature && self.chunks == other.chunks
+    }
+
+    /// Runs several edits as one: if `f` fails, every change it made is rolled back and its
+    /// error returned.
+    pub fn try_apply<F: FnOnce(&mut Png) -> Result<(), Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Error> {
+        let original = self.clone();
+        f(self).inspect_err(|_| *self = original)
+    }
+
+    /// Keyword and text of every tEXt, zTXt and iTXt chunk in file order, keeping repeated
+    /// keywords. Chunks that fail to decode are skipped.
+    pub fn all_text_entries(&self) -> Vec<(String, String)> {
+        self.chunks
+            .iter()
+            .filter(|chunk| TextChunk::is_text_chunk(chunk))
+            .filter_map(|chunk| TextChunk::try_from(chunk).ok())
+            .map(|text| (text.keyword().to_string(), text.text().to_string()))
+            .collect()
+    }
+
+    /// Stores application data in a chunk of type `app_id`, replacing one set earlier. The data
+    /// is packed as the records app id, big-endian version and payload so a reader can tell its
+    /// own chunk from another application's that happens to use the same type. `app_id` must
+    /// be an ancillary private chunk type so decoders skip it.
+    pub fn set_app_metadata(
+        &mut self,
+        app_id: &str,
+        version: u32,
+        payload: &[u8],
+    ) -> Result<(), Error> {
+        let chunk_type = ChunkType::from_str(app_id)?;
+        if chunk_type.is_critical() || chunk_type.is_public() || !chunk_type.is_reserved_bit_valid()
+        {
+            return Err(Box::new(PngError::InvalidAppId(app_id.to_string())));
+        }
+        let chunk = Chunk::pack_records(
+            chunk_type,
+            &[app_id.as_bytes(), &version.to_be_bytes(), payload],
+        )?;
+        match self.chunks.iter().position(|chunk| chunk.is_type(app_id)) {
+            Some(ind) => self.chunks[ind] = chunk,
+            None => self.insert_before_iend(chunk),
+        }
+        Ok(())
+    }
+
+    /// Version and payload stored by [`Png::set_app_metadata`], if there is a chunk of type
+    /// `app_id` laid out that way.
+    pub fn app_metadata(&self, app_id: &str) -> Option<(u32, Vec<u8>)> {
+        let records = self.chunk_by_type(app_id)?.unpack_records().ok()?;
+        match <[Vec<u8>; 3]>::try_from(records).ok()? {
+            [id, version, payload] if id == app_id.as_bytes() => {
+                Some((u32::from_be_bytes(version.try_into().ok()?), payload))
+            }
+            _ => None,
+        }
+    }
+
+    /// The XMP packet stored in the iTXt chunk with keyword `XML:com.adobe.xmp`, if any.
+    pub fn xmp(&self) -> Option<String> {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.is_type("iTXt"))
+            .filter_map(|chunk| TextChunk::try_from(chunk).ok())
+            .find(|text| text.keyword() == Png::XMP_KEYWORD)
+            .map(|text| text.text().to_string())
+    }
+
+    /// Gathers the common metrics about the file in one pass.
+    pub fn statistics(&self) -> PngStats {
+        let mut per_type: Vec<ChunkTypeStats> = Vec::new();
+        for chunk in self.chunks.iter() {
+            let chunk_type = chunk.chunk_type().to_string();
+            match per_type
+                .iter_mut()
+                .find(|entry| entry.chunk_type == chunk_type)
+            {
+                Some(entry) => {
+                    entry.count += 1;
+                    entry.size += chunk.data().len();
+                }
+                None => per_type.push(ChunkTypeStats {
+                    chunk_type,
+                    count: 1,
+                    size: chunk.data().len(),
+                }),
+            }
+        }
+
+        // Measured from the lengths rather than by serializing the whole file
+        let chunks_size: usize = self
+            .chunks
+            .iter()
+            .map(|chunk| Chunk::serialized_size(chunk.length() as usize))
+            .sum();
+        let total_size = self.signature().len() + chunks_size;
+
+        PngStats {
+            chunk_count: self.chunks.len(),
+            total_size,
+            per_type,
+            has_text: self.chunks.iter().any(TextChunk::is_text_chunk),
+            has_exif: self.chunk_by_type("eXIf").is_some(),
+            is_animated: self.chunk_by_type("acTL").is_some(),
+            has_color_profile: Png::COLOR_PROFILE_CHUNKS
+                .iter()
+                .any(|chunk_type| self.chunk_by_type(chunk_type).is_some()),
+            dimensions: self
+                .chunk_by_type("IHDR")
+                .and_then(|chunk| Ihdr::try_from(chunk).ok())
+                .map(|ihdr| (ihdr.width(), ihdr.height())),
+        }
+    }
+
+    /// Counts of (critical, ancillary public, ancillary private) chunks, a quick look at how
+    /// much non-standard data the file carries.
+    pub fn chunk_flag_summary(&self) -> (usize, usize, usize) {
+        self.chunks.iter().map(|chunk| chunk.chunk_type()).fold(
+            (0, 0, 0),
+            |(critical, public, private), chunk_type| {
+                if chunk_type.is_critical() {
+                    (critical + 1, public, private)
+                } else if chunk_type.is_public() {
+                    (critical, public + 1, private)
+                } else {
+                    (critical, public, private + 1)
+                }
+            },
+        )
+    }
+
+    /// Indices of the chunks whose data contains `needle`, e.g. the magic number of a file
+    /// hidden inside. An empty needle matches nothing.
+    pub fn find_chunks_containing(&self, needle: &[u8]) -> Vec<usize> {
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| chunk.data().windows(needle.len()).any(|w| w == needle))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Rewrites every tEXt and zTXt chunk as iTXt so all text is UTF-8, keywords and values
+    /// are kept as they are. Chunks that fail to decode are left untouched.
+    pub fn normalize_text_encoding(&mut self) {
+        for chunk in self.chunks.iter_mut() {
+            if !TextChunk::is_text_chunk(chunk) {
+                continue;
+            }
+            if let Ok(text) = TextChunk::try_from(&*chunk) {
+                if text.kind() != TextKind::InternationalText {
+                    *chunk = text.to_international().to_chunk();
+                }
+            }
+        }
+    }
+
+    /// Reads the whole file at `path` and parses it with `try_from`, handing over the buffer so
+    /// the image data doesn't need copying.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Png, Error> {
+        Png::try_from(std::fs::read(path)?)
+    }
+
+    /// Parses a PNG like `try_from` but keeps chunks whose CRC doesn't match, use
+    /// `assert_renderable` to find them afterwards.
+    pub fn try_from_unchecked(value: &[u8]) -> Result<Png, Error> {
+        Png::parse_with(value, Chunk::try_from_unchecked)
+    }
+
+    /// Parses without checking CRCs and accepting chunk types with the reserved bit set. Such
+    /// chunks are kept and reported as warnings since they use a namespace the spec keeps for
+    /// future extensions.
+    pub fn parse_lenient(value: &[u8]) -> Result<(Png, Vec<ParseWarning>), Error> {
+        let png = Png::parse_with(value, Chunk::try_from_lenient)?;
+        let warnings = png
+            .chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| !chunk.chunk_type().is_reserved_bit_valid())
+            .map(|(index, chunk)| ParseWarning::ReservedBit {
+                index,
+                chunk_type: *chunk.chunk_type(),
+            })
+            .collect();
+        Ok((png, warnings))
+    }
+
+    /// Parses a bare chunk stream with the 8-byte signature stripped, as found when PNG data is
+    /// embedded in other containers. The standard signature is added back on `as_bytes`.
+    pub fn from_chunks_bytes(bytes: &[u8]) -> Result<Png, Error> {
+        let chunks = Png::parse_chunks(bytes, Chunk::try_from_prefix)?;
+        Ok(Png::from_chunks(chunks))
+    }
+
+    /// Parses several PNG files stored back to back, each running up to and including its
+    /// IEND. Fails once more than `max_images` files are found so untrusted input can't make
+    /// us parse without bound.
+    pub fn parse_all(bytes: &[u8], max_images: usize) -> Result<Vec<Png>, Error> {
+        let mut images = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            if images.len() == max_images {
+                return Err(Box::new(PngError::TooManyImages(max_images)));
+            }
+            if !bytes[offset..].starts_with(&Png::STANDARD_HEADER) {
+                return Err(Box::new(PngError::InvalidHeader));
+            }
+            offset += Png::STANDARD_HEADER.len();
+
+            let mut chunks = Vec::new();
+            loop {
+                let (chunk, next) = Chunk::parse_at(bytes, offset)?;
+                offset = next;
+                let is_iend = chunk.is_type("IEND");
+                chunks.push(chunk);
+                if is_iend {
+                    break;
+                }
+            }
+            images.push(Png::from_chunks(chunks));
         }
+        Ok(images)
+    }
+
+    /// Parses like `try_from` but expecting `signature` instead of the standard one.
+    pub fn parse_with_signature(value: &[u8], signature: [u8; 8]) -> Result<Png, Error> {
+        Png::parse_signed(value, signature, Chunk::try_from_prefix)
+    }
+
+    fn parse_with(
+        value: &[u8],
+        parse_chunk: impl Fn(&[u8]) -> Result<Chunk, Error>,
+    ) -> Result<Png, Error> {
+        Png::parse_signed(value, Png::STANDARD_HEADER, parse_chunk)
+    }
+
+    fn parse_signed(
+        value: &[u8],
+        signature: [u8; 8],
+        parse_chunk: impl Fn(&[u8]) -> Result<Chunk, Error>,
+    ) -> Result<Png, Error> {
+        // Header, shorter input can't hold it either
+        let Some(rest) = value.strip_prefix(&signature) else {
+            return Err(Box::new(PngError::InvalidHeader));
+        };
 
         // We finished reading the header, now what's left are the chunks
+        let chunks = Png::parse_chunks(rest, parse_chunk)?;
+        Ok(Png::with_signature(signature, chunks))
+    }
+
+    fn parse_chunks(
+        value: &[u8],
+        parse_chunk: impl Fn(&[u8]) -> Result<Chunk, Error>,
+    ) -> Result<Vec<Chunk>, Error> {
         // TODO: there's got to be a nicer way than advancing a pointer and reslicing each time
-        let mut index = Png::STANDARD_HEADER.len();
+        let mut index = 0;
 
         // parse one chunk at a time
         let mut chunks = Vec::new();
 
         while index < value.len() {
-            println!("Index is at {index}");
             let bytes = &value[index..];
-            let chunk = Chunk::try_from(bytes)?;
-            index += chunk.length() as usize + Chunk::METADATA_BYTES;
+            let chunk = parse_chunk(bytes)?;
+            index += Chunk::serialized_size(chunk.length() as usize);
 
             chunks.push(chunk);
         }
-        Ok(Png { chunks })
+        Ok(chunks)
+    }
+
+    /// Checks everything a decoder needs to render the image: IHDR comes first and is valid,
+    /// there is at least one IDAT, IEND comes last and every stored CRC matches.
+    /// All problems found are returned instead of stopping at the first one.
+    pub fn assert_renderable(&self) -> Result<(), Vec<Error>> {
+        let problems: Vec<Error> = self
+            .ihdr_problem()
+            .into_iter()
+            .chain(self.idat_problem())
+            .chain(self.iend_problem())
+            .chain(self.crc_problems())
+            .collect();
+
+        match problems.is_empty() {
+            true => Ok(()),
+            false => Err(problems),
+        }
+    }
+
+    /// Whether any ancillary chunk has a private type, i.e. one outside the PNG spec and its
+    /// registered extensions that may carry data no standard tool knows about. Private critical
+    /// chunks aren't counted since the image can't be decoded without them anyway.
+    pub fn has_private_chunks(&self) -> bool {
+        self.chunks.iter().any(Png::is_private_ancillary)
+    }
+
+    /// Fails listing the private ancillary chunk types when [`Png::has_private_chunks`], for
+    /// publishing workflows that forbid them.
+    pub fn assert_no_private_chunks(&self) -> Result<(), Error> {
+        let private: Vec<String> = self
+            .chunks
+            .iter()
+            .filter(|chunk| Png::is_private_ancillary(chunk))
+            .map(|chunk| chunk.chunk_type().to_string())
+            .collect();
+        match private.is_empty() {
+            true => Ok(()),
+            false => Err(Box::new(PngError::PrivateChunks(private))),
+        }
+    }
+
+    fn is_private_ancillary(chunk: &Chunk) -> bool {
+        let chunk_type = chunk.chunk_type();
+        !chunk_type.is_public() && !chunk_type.is_critical()
+    }
+
+    /// Checks the leading 8 bytes against the PNG signature, on mismatch reporting which bytes
+    /// differ and guessing common causes like a different format or line ending conversion.
+    pub fn validate_signature_strict(bytes: &[u8]) -> Result<(), Error> {
+        let differing: Vec<usize> = (0..Png::STANDARD_HEADER.len())
+            .filter(|&i| bytes.get(i) != Some(&Png::STANDARD_HEADER[i]))
+            .collect();
+        if differing.is_empty() {
+            return Ok(());
+        }
+        Err(Box::new(PngError::SignatureMismatch(
+            differing,
+            Png::signature_hint(bytes),
+        )))
+    }
+
+    fn signature_hint(bytes: &[u8]) -> Option<&'static str> {
+        if bytes.len() < Png::STANDARD_HEADER.len() {
+            return Some("the file is shorter than a signature");
+        }
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some("this looks like a JPEG file");
+        }
+        if bytes.starts_with(b"GIF8") {
+            return Some("this looks like a GIF file");
+        }
+        if bytes[..4] == Png::STANDARD_HEADER[..4] {
+            if bytes[4..6] == *b"\n\n" {
+                return Some("bytes 4-5 are LF-LF, the file may have been line-ending converted");
+            }
+            if bytes[4..6] == *b"\n\x1a" {
+                return Some(
+                    "the CR at byte 4 is gone, the file may have been line-ending converted",
+                );
+            }
+            if bytes[4..8] == *b"\r\n\x1a\r" {
+                return Some(
+                    "the LF at byte 7 became CR-LF, the file may have been line-ending converted",
+                );
+            }
+        }
+        if bytes[0] == Png::STANDARD_HEADER[0] & 0x7F && bytes[1..4] == Png::STANDARD_HEADER[1..4] {
+            return Some(
+                "the high bit of byte 0 is cleared, the file may have been sent as 7-bit text",
+            );
+        }
+        None
+    }
+
+    /// Quick pass/fail check of the signature, structure (IHDR first and valid, IEND last) and
+    /// stored CRCs, returning the first problem found. Use `assert_renderable` to get them all.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.signature() != &Png::STANDARD_HEADER {
+            return Err(Box::new(PngError::InvalidHeader));
+        }
+        match self
+            .ihdr_problem()
+            .into_iter()
+            .chain(self.iend_problem())
+            .chain(self.crc_problems())
+            .next()
+        {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn ihdr_problem(&self) -> Option<Error> {
+        match self.first_chunk() {
+            Some(chunk) if chunk.is_type("IHDR") => Ihdr::try_from(chunk)
+                .err()
+                .map(|e| Box::new(PngError::InvalidIhdr(e)) as Error),
+            _ => Some(Box::new(PngError::FirstChunkNotIhdr)),
+        }
+    }
+
+    fn idat_problem(&self) -> Option<Error> {
+        match self.chunks.iter().any(|chunk| chunk.is_type("IDAT")) {
+            true => None,
+            false => Some(Box::new(PngError::MissingIdat)),
+        }
+    }
+
+    fn iend_problem(&self) -> Option<Error> {
+        match self.last_chunk() {
+            Some(chunk) if chunk.is_type("IEND") => None,
+            _ => Some(Box::new(PngError::LastChunkNotIend)),
+        }
+    }
+
+    fn crc_problems(&self) -> impl Iterator<Item = Error> + '_ {
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| !chunk.crc_matches_data())
+            .map(|(index, _)| Box::new(PngError::CrcMismatch(index)) as Error)
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let Some(rest) = value.strip_prefix(&Png::STANDARD_HEADER) else {
+            return Err(Box::new(PngError::InvalidHeader));
+        };
+        // No chunk can hold more data than what's left of the input
+        let chunks =
+            ChunkReader::with_limit(rest, rest.len()).collect::<Result<Vec<Chunk>, Error>>()?;
+        Ok(Png::from_chunks(chunks))
+    }
+}
+
+/// For buffers straight from `fs::read`. The largest payload, normally the IDAT, is moved into
+/// the buffer's own allocation without copying, only the smaller chunks are copied out.
+impl TryFrom<Vec<u8>> for Png {
+    type Error = Error;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        if !value.starts_with(&Png::STANDARD_HEADER) {
+            return Err(Box::new(PngError::InvalidHeader));
+        }
+        let mut spans = Vec::new();
+        let mut offset = Png::STANDARD_HEADER.len();
+        while offset < value.len() {
+            let (span, next) = Chunk::locate_at(&value, offset)?;
+            spans.push(span);
+            offset = next;
+        }
+
+        let Some(largest) = (0..spans.len()).max_by_key(|&i| spans[i].len()) else {
+            return Ok(Png::new());
+        };
+        let moved = spans.remove(largest);
+        let mut chunks: Vec<Chunk> = spans
+            .iter()
+            .map(|span| Chunk::from_span(span, &value))
+            .collect();
+        chunks.insert(largest, Chunk::from_span_owned(&moved, value));
+        Ok(Png::from_chunks(chunks))
+    }
+}
+
+impl Default for Png {
+    fn default() -> Self {
+        Png::new()
+    }
+}
+
+/// Chunk by position, panicking when out of range like `Vec`.
+impl std::ops::Index<usize> for Png {
+    type Output = Chunk;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.chunks[index]
+    }
+}
+
+impl AsRef<[Chunk]> for Png {
+    fn as_ref(&self) -> &[Chunk] {
+        &self.chunks
     }
 }
 
@@ -92,6 +841,72 @@ impl std::fmt::Display for Png {
 enum PngError {
     InvalidHeader,
     UnknownChunkType,
+    FirstChunkNotIhdr,
+    MissingIdat,
+    LastChunkNotIend,
+    MissingIend,
+    CrcMismatch(usize),
+    InvalidIhdr(Error),
+    Interlaced,
+    /// Indices of the differing bytes and a guess at the cause.
+    SignatureMismatch(Vec<usize>, Option<&'static str>),
+    InvalidAppId(String),
+    PrivateChunks(Vec<String>),
+    TooManyImages(usize),
+}
+
+impl PngError {
+    fn kind(&self) -> &'static str {
+        match self {
+            PngError::InvalidHeader => "invalid_header",
+            PngError::UnknownChunkType => "unknown_chunk_type",
+            PngError::FirstChunkNotIhdr => "first_chunk_not_ihdr",
+            PngError::MissingIdat => "missing_idat",
+            PngError::LastChunkNotIend => "last_chunk_not_iend",
+            PngError::MissingIend => "missing_iend",
+            PngError::CrcMismatch(_) => "crc_mismatch",
+            PngError::InvalidIhdr(_) => "invalid_ihdr",
+            PngError::Interlaced => "interlaced",
+            PngError::SignatureMismatch(..) => "invalid_header",
+            PngError::InvalidAppId(_) => "invalid_app_id",
+            PngError::PrivateChunks(_) => "private_chunk",
+            PngError::TooManyImages(_) => "too_many_images",
+        }
+    }
+}
+
+/// Short machine readable name for an error or warning returned while loading or checking a
+/// PNG, errors from outside this module (I/O, malformed chunks) are reported as `"parse"`.
+pub fn problem_kind(error: &Error) -> &'static str {
+    if let Some(e) = error.downcast_ref::<PngError>() {
+        return e.kind();
+    }
+    match error.downcast_ref::<ParseWarning>() {
+        Some(ParseWarning::ReservedBit { .. }) => "reserved_bit",
+        None => "parse",
+    }
+}
+
+/// Problems found by [`Png::parse_lenient`] that don't stop the file from being read.
+#[derive(Debug)]
+pub enum ParseWarning {
+    ReservedBit { index: usize, chunk_type: ChunkType },
+}
+
+impl std::error::Error for ParseWarning {}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::ReservedBit { index, chunk_type } => {
+                write!(
+                    f,
+                    "Chunk {} ({}) has the reserved bit set!",
+                    index, chunk_type
+                )
+            }
+        }
+    }
 }
 
 impl std::error::Error for PngError {}
@@ -101,6 +916,33 @@ impl std::fmt::Display for PngError {
         match self {
             PngError::UnknownChunkType => write!(f, "Chunk type not found!"),
             PngError::InvalidHeader => write!(f, "Invalid Header bytes!"),
+            PngError::FirstChunkNotIhdr => write!(f, "First chunk is not IHDR!"),
+            PngError::MissingIdat => write!(f, "No IDAT chunk found!"),
+            PngError::LastChunkNotIend => write!(f, "Last chunk is not IEND!"),
+            PngError::MissingIend => write!(f, "No IEND chunk found!"),
+            PngError::CrcMismatch(index) => write!(f, "CRC Mismatch in chunk {}!", index),
+            PngError::InvalidIhdr(e) => write!(f, "Invalid IHDR chunk! {}", e),
+            PngError::Interlaced => write!(f, "Interlaced images are not supported!"),
+            PngError::SignatureMismatch(differing, hint) => {
+                write!(f, "Signature mismatch at bytes {:?}!", differing)?;
+                match hint {
+                    Some(hint) => write!(f, " Possibly {}", hint),
+                    None => Ok(()),
+                }
+            }
+            PngError::InvalidAppId(app_id) => {
+                write!(f, "{} is not an ancillary private chunk type!", app_id)
+            }
+            PngError::PrivateChunks(chunk_types) => {
+                write!(
+                    f,
+                    "Private ancillary chunks found: {}",
+                    chunk_types.join(", ")
+                )
+            }
+            PngError::TooManyImages(limit) => {
+                write!(f, "More than {} images in the input!", limit)
+            }
         }
     }
 }
@@ -115,13 +957,11 @@ mod tests {
     use std::str::FromStr;
 
     fn testing_chunks() -> Vec<Chunk> {
-        let mut chunks = Vec::new();
-
-        chunks.push(chunk_from_strings("FrSt", "I am the first chunk").unwrap());
-        chunks.push(chunk_from_strings("miDl", "I am another chunk").unwrap());
-        chunks.push(chunk_from_strings("LASt", "I am the last chunk").unwrap());
-
-        chunks
+        vec![
+            chunk_from_strings("FrSt", "I am the first chunk").unwrap(),
+            chunk_from_strings("miDl", "I am another chunk").unwrap(),
+            chunk_from_strings("LASt", "I am the last chunk").unwrap(),
+        ]
     }
 
     fn testing_png() -> Png {
@@ -129,11 +969,27 @@ mod tests {
         Png::from_chunks(chunks)
     }
 
-    fn chunk_from_strings(chunk_type: &str, data: &str) -> Result<Chunk, Error> {
-        let chunk_type = ChunkType::from_str(chunk_type)?;
-        let data: Vec<u8> = data.bytes().collect();
+    /// A 1x1 8-bit RGBA image with a single red pixel.
+    fn minimal_png() -> Png {
+        Png::from_chunks(vec![ihdr_chunk(), idat_chunk(), Chunk::iend()])
+    }
+
+    fn ihdr_chunk() -> Chunk {
+        Chunk::new(
+            ChunkType::from_str("IHDR").unwrap(),
+            vec![0, 0, 0, 1, 0, 0, 0, 1, 8, 6, 0, 0, 0],
+        )
+    }
+
+    fn idat_chunk() -> Chunk {
+        Chunk::new(
+            ChunkType::from_str("IDAT").unwrap(),
+            vec![120, 156, 99, 248, 207, 192, 240, 31, 0, 5, 0, 1, 255],
+        )
+    }
 
-        Ok(Chunk::new(chunk_type, data))
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Result<Chunk, Error> {
+        Chunk::from_strings(chunk_type, data)
     }
 
     #[test]
@@ -144,6 +1000,19 @@ mod tests {
         assert_eq!(png.chunks().len(), 3);
     }
 
+    #[test]
+    fn test_from_chunks_as_bytes() {
+        let png = Png::from_chunks(testing_chunks());
+        let bytes = png.as_bytes();
+
+        assert!(bytes.starts_with(&Png::STANDARD_HEADER));
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+        assert_eq!(&bytes[Png::STANDARD_HEADER.len()..], chunk_bytes.as_slice());
+    }
+
     #[test]
     fn test_valid_from_bytes() {
         let chunk_bytes: Vec<u8> = testing_chunks()
@@ -181,21 +1050,111 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_chunk() {
-        let mut chunk_bytes: Vec<u8> = testing_chunks()
-            .into_iter()
-            .flat_map(|chunk| chunk.as_bytes())
-            .collect();
+    fn test_from_file() {
+        let path =
+            std::env::temp_dir().join(format!("pngmehd-{}-from-file.png", std::process::id()));
+        std::fs::write(&path, minimal_png().as_bytes()).unwrap();
+        let png = Png::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(png.chunks().len(), minimal_png().chunks().len());
 
-        #[rustfmt::skip]
-        let mut bad_chunk = vec![
-            0, 0, 0, 5,         // length
-            32, 117, 83, 116,   // Chunk Type (bad)
-            65, 64, 65, 66, 67, // Data
-            1, 2, 3, 4, 5       // CRC (bad)
-        ];
+        assert!(Png::from_file(&path).is_err());
+    }
 
-        chunk_bytes.append(&mut bad_chunk);
+    #[test]
+    fn test_parse_all() {
+        let blob = [minimal_png().as_bytes(), PNG_FILE.to_vec()].concat();
+        let images = Png::parse_all(&blob, 2).unwrap();
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].as_bytes(), minimal_png().as_bytes());
+        assert_eq!(images[1].as_bytes(), PNG_FILE.to_vec());
+
+        assert!(Png::parse_all(&blob[..blob.len() - 1], 2).is_err());
+    }
+
+    #[test]
+    fn test_parse_all_image_limit() {
+        let blob = minimal_png().as_bytes().repeat(3);
+        assert_eq!(Png::parse_all(&blob, 3).unwrap().len(), 3);
+
+        let error = Png::parse_all(&blob, 2).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<PngError>(),
+            Some(PngError::TooManyImages(2))
+        ));
+    }
+
+    #[cfg(feature = "encode")]
+    #[test]
+    fn test_from_rgba_round_trip() {
+        #[rustfmt::skip]
+        let pixels = [
+            255, 0, 0, 255,     0, 255, 0, 255,
+            0, 0, 255, 128,     255, 255, 255, 0,
+        ];
+        let png = Png::from_rgba(2, 2, &pixels).unwrap();
+        assert!(png.assert_renderable().is_ok());
+
+        let png = Png::try_from(png.as_bytes().as_ref()).unwrap();
+        assert_eq!(png.to_raw_pixels().unwrap(), pixels);
+
+        assert!(Png::from_rgba(2, 2, &pixels[..12]).is_err());
+        assert!(Png::from_rgba(0, 2, &[]).is_err());
+    }
+
+    #[test]
+    fn test_short_header() {
+        for len in [0, 3, 7] {
+            let error = Png::try_from(&PNG_FILE[..len]).err().unwrap();
+            assert!(matches!(
+                error.downcast_ref::<PngError>(),
+                Some(PngError::InvalidHeader)
+            ));
+            assert!(Png::try_from_unchecked(&PNG_FILE[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_truncated_mid_chunk() {
+        let bytes = testing_png().as_bytes();
+        // Cuts into the data of the last chunk
+        let png = Png::try_from(&bytes[..bytes.len() - 10]);
+        assert!(png.is_err());
+
+        let mut bytes = bytes;
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        let error = Png::try_from(bytes.as_ref()).err().unwrap();
+        assert!(error.to_string().contains("CRC Mismatch"));
+    }
+
+    #[test]
+    fn test_huge_declared_length() {
+        let mut bytes = Png::STANDARD_HEADER.to_vec();
+        bytes.extend_from_slice(&0xFFFF_FFF0u32.to_be_bytes());
+        bytes.extend_from_slice(b"IHDR");
+        bytes.extend_from_slice(&[0; 8]);
+
+        let error = Png::try_from(bytes.as_slice()).err().unwrap();
+        assert!(error.to_string().contains("4294967280"));
+    }
+
+    #[test]
+    fn test_invalid_chunk() {
+        let mut chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        #[rustfmt::skip]
+        let mut bad_chunk = vec![
+            0, 0, 0, 5,         // length
+            32, 117, 83, 116,   // Chunk Type (bad)
+            65, 64, 65, 66, 67, // Data
+            1, 2, 3, 4, 5       // CRC (bad)
+        ];
+
+        chunk_bytes.append(&mut bad_chunk);
 
         let png = Png::try_from(chunk_bytes.as_ref());
 
@@ -217,6 +1176,29 @@ mod tests {
         assert_eq!(&chunk.data_as_string().unwrap(), "I am the first chunk");
     }
 
+    #[test]
+    fn test_chunk_by_type_absent() {
+        let png = testing_png();
+        assert!(png.chunk_by_type("NoPe").is_none());
+        assert!(png.chunk_by_type("bad").is_none());
+        assert!(png.chunks_by_type("NoPe").is_empty());
+        assert!(png.chunks_by_type("bad").is_empty());
+    }
+
+    #[test]
+    fn test_chunks_by_type() {
+        let mut png = minimal_png();
+        png.append_chunk(chunk_from_strings("tEXt", "One").unwrap());
+        png.append_chunk(chunk_from_strings("tEXt", "Two").unwrap());
+
+        let texts = png.chunks_by_type("tEXt");
+        assert_eq!(texts.len(), 2);
+        assert_eq!(texts[0].data_as_string().unwrap(), "One");
+        assert_eq!(texts[1].data_as_string().unwrap(), "Two");
+        assert_eq!(png.chunk_by_type("tEXt"), Some(texts[0]));
+        assert_eq!(png.chunks_by_type("IHDR").len(), 1);
+    }
+
     #[test]
     fn test_append_chunk() {
         let mut png = testing_png();
@@ -230,11 +1212,37 @@ mod tests {
     fn test_remove_chunk() {
         let mut png = testing_png();
         png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
-        png.remove_chunk("TeSt").unwrap();
+        png.remove_first_chunk_by_type("TeSt").unwrap();
         let chunk = png.chunk_by_type("TeSt");
         assert!(chunk.is_none());
     }
 
+    #[test]
+    fn test_append_chunk_before_iend() {
+        let mut png = minimal_png();
+        png.append_chunk(chunk_from_strings("TeSt", "First").unwrap());
+        png.append_chunk(chunk_from_strings("TeSt", "Second").unwrap());
+
+        let types: Vec<String> = png
+            .chunks()
+            .iter()
+            .map(|chunk| chunk.chunk_type().to_string())
+            .collect();
+        assert_eq!(types, ["IHDR", "IDAT", "TeSt", "TeSt", "IEND"]);
+        assert_eq!(png[3].data_as_string().unwrap(), "Second");
+    }
+
+    #[test]
+    fn test_remove_missing_chunk() {
+        let mut png = minimal_png();
+        let error = png.remove_first_chunk_by_type("TeSt").err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<PngError>(),
+            Some(PngError::UnknownChunkType)
+        ));
+        assert_eq!(png.chunks().len(), 3);
+    }
+
     #[test]
     fn test_png_from_image_file() {
         let png = Png::try_from(&PNG_FILE[..]);
@@ -249,6 +1257,822 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_minimal_png_is_renderable() {
+        assert!(minimal_png().assert_renderable().is_ok());
+    }
+
+    #[test]
+    fn test_dice_png_is_renderable() {
+        let png = Png::try_from(&PNG_FILE[..]).unwrap();
+        assert!(png.assert_renderable().is_ok());
+    }
+
+    #[test]
+    fn test_missing_idat_is_not_renderable() {
+        let mut png = minimal_png();
+        png.remove_first_chunk_by_type("IDAT").unwrap();
+
+        let problems = png.assert_renderable().unwrap_err();
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            problems[0].downcast_ref::<PngError>(),
+            Some(PngError::MissingIdat)
+        ));
+    }
+
+    #[test]
+    fn test_not_renderable_collects_all_problems() {
+        let png = testing_png();
+        let problems = png.assert_renderable().unwrap_err();
+        assert_eq!(problems.len(), 3);
+    }
+
+    #[test]
+    fn test_unchecked_keeps_crc_mismatch() {
+        let mut bytes = minimal_png().as_bytes();
+        // Corrupt the IHDR crc, right after the header, length, type and data
+        bytes[8 + 4 + 4 + 13] ^= 0xFF;
+
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+
+        let png = Png::try_from_unchecked(bytes.as_ref()).unwrap();
+        let problems = png.assert_renderable().unwrap_err();
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            problems[0].downcast_ref::<PngError>(),
+            Some(PngError::CrcMismatch(0))
+        ));
+    }
+
+    #[test]
+    fn test_insert_before_iend() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("TeSt", "Message").unwrap());
+        assert_eq!(png.chunks().len(), 4);
+        assert_eq!(&png.chunks()[2].chunk_type().to_string(), "TeSt");
+        assert_eq!(&png.chunks()[3].chunk_type().to_string(), "IEND");
+    }
+
+    #[test]
+    fn test_insert_many_before_iend() {
+        let mut png = minimal_png();
+        png.insert_many_before_iend(vec![
+            chunk_from_strings("OnEe", "1").unwrap(),
+            chunk_from_strings("TwOo", "2").unwrap(),
+            chunk_from_strings("ThRe", "3").unwrap(),
+        ])
+        .unwrap();
+
+        let types: Vec<String> = png
+            .chunks()
+            .iter()
+            .map(|chunk| chunk.chunk_type().to_string())
+            .collect();
+        assert_eq!(types, ["IHDR", "IDAT", "OnEe", "TwOo", "ThRe", "IEND"]);
+
+        let mut png = testing_png();
+        let result = png.insert_many_before_iend(vec![chunk_from_strings("OnEe", "1").unwrap()]);
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<PngError>(),
+            Some(PngError::MissingIend)
+        ));
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_merge_ancillary_from() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("tEXt", "Author\0Lemon").unwrap());
+
+        let mut other = minimal_png();
+        other.insert_before_iend(chunk_from_strings("tEXt", "Author\0Lemon").unwrap());
+        other.insert_before_iend(chunk_from_strings("tEXt", "Comment\0Edited").unwrap());
+        // Unsafe to copy, should stay behind
+        other.insert_before_iend(chunk_from_strings("ruST", "Hidden").unwrap());
+
+        png.merge_ancillary_from(&other);
+
+        let texts: Vec<String> = png
+            .chunks()
+            .iter()
+            .filter(|chunk| chunk.is_type("tEXt"))
+            .map(|chunk| chunk.data_as_string().unwrap())
+            .collect();
+        assert_eq!(texts, vec!["Author\0Lemon", "Comment\0Edited"]);
+        assert!(png.chunk_by_type("ruST").is_none());
+        assert_eq!(png.chunks().len(), 5);
+        assert_eq!(&png.chunks()[4].chunk_type().to_string(), "IEND");
+    }
+
+    #[test]
+    fn test_strip_color_profile() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("tEXt", "Comment\0Profiled").unwrap());
+        png.chunks
+            .insert(1, chunk_from_strings("gAMA", "gama").unwrap());
+        png.chunks
+            .insert(1, chunk_from_strings("sRGB", "s").unwrap());
+        png.chunks
+            .insert(1, chunk_from_strings("iCCP", "icc profile").unwrap());
+        png.chunks
+            .insert(1, chunk_from_strings("cHRM", "chromaticities").unwrap());
+
+        assert_eq!(png.strip_color_profile(), 4);
+
+        let types: Vec<String> = png
+            .chunks()
+            .iter()
+            .map(|chunk| chunk.chunk_type().to_string())
+            .collect();
+        assert_eq!(types, vec!["IHDR", "IDAT", "tEXt", "IEND"]);
+        assert_eq!(png.strip_color_profile(), 0);
+    }
+
+    #[test]
+    fn test_validate_good_file() {
+        let png = Png::try_from(&PNG_FILE[..]).unwrap();
+        assert!(png.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_crc_corrupt_file() {
+        let mut bytes = PNG_FILE.to_vec();
+        // Corrupt the IHDR crc, right after the header, length, type and data
+        bytes[8 + 4 + 4 + 13] ^= 0xFF;
+
+        let png = Png::try_from_unchecked(bytes.as_ref()).unwrap();
+        let e = png.validate().unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<PngError>(),
+            Some(PngError::CrcMismatch(0))
+        ));
+    }
+
+    #[test]
+    fn test_validate_reports_structure_first() {
+        let mut bytes = minimal_png().as_bytes();
+        // Corrupt the IHDR crc, then drop the IEND chunk
+        bytes[8 + 4 + 4 + 13] ^= 0xFF;
+        bytes.truncate(bytes.len() - Chunk::METADATA_BYTES);
+
+        let png = Png::try_from_unchecked(bytes.as_ref()).unwrap();
+        let e = png.validate().unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<PngError>(),
+            Some(PngError::LastChunkNotIend)
+        ));
+    }
+
+    #[test]
+    fn test_from_chunks_bytes() {
+        let stream = &PNG_FILE[Png::STANDARD_HEADER.len()..];
+        let png = Png::from_chunks_bytes(stream).unwrap();
+
+        assert_eq!(&png.chunks()[0].chunk_type().to_string(), "IHDR");
+        assert_eq!(png.as_bytes(), PNG_FILE.to_vec());
+    }
+
+    #[test]
+    fn test_from_chunks_bytes_with_signature() {
+        // The signature isn't a chunk, so it can't be parsed as one
+        assert!(Png::from_chunks_bytes(&PNG_FILE[..]).is_err());
+    }
+
+    #[test]
+    fn test_first_and_last_chunk() {
+        let png = minimal_png();
+        assert_eq!(&png.first_chunk().unwrap().chunk_type().to_string(), "IHDR");
+        assert_eq!(&png.last_chunk().unwrap().chunk_type().to_string(), "IEND");
+
+        let empty = Png::new();
+        assert!(empty.first_chunk().is_none());
+        assert!(empty.last_chunk().is_none());
+    }
+
+    #[test]
+    fn test_rename_chunk_type() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("ruSt", "Another message").unwrap());
+        png.chunks
+            .insert(0, chunk_from_strings("ruSt", "Message").unwrap());
+        let old_crcs: Vec<u32> = png
+            .chunks_by_type("ruSt")
+            .iter()
+            .map(|chunk| chunk.crc())
+            .collect();
+
+        assert_eq!(png.rename_chunk_type("ruSt", "myAp").unwrap(), 2);
+
+        assert!(png.chunk_by_type("ruSt").is_none());
+        let renamed = png.chunks_by_type("myAp");
+        assert_eq!(renamed.len(), 2);
+        assert_eq!(&renamed[0].data_as_string().unwrap(), "Message");
+        assert_eq!(&renamed[1].data_as_string().unwrap(), "Another message");
+        for (chunk, old_crc) in renamed.iter().zip(old_crcs) {
+            assert_ne!(chunk.crc(), old_crc);
+            // Round trips through the CRC checked parser
+            assert!(Chunk::try_from(chunk.as_bytes().as_ref()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_rename_chunk_type_invalid_target() {
+        let mut png = testing_png();
+        assert!(png.rename_chunk_type("FrSt", "my1p").is_err());
+        assert!(png.rename_chunk_type("FrSt", "toolong").is_err());
+        assert!(png.chunk_by_type("FrSt").is_some());
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_content_hash_matches_buffered() {
+        use sha2::{Digest, Sha256};
+
+        let png = Png::try_from(&PNG_FILE[..]).unwrap();
+        let buffered: [u8; 32] = Sha256::digest(png.as_bytes()).into();
+        assert_eq!(png.content_hash(), buffered);
+        assert_ne!(minimal_png().content_hash(), buffered);
+    }
+
+    #[test]
+    fn test_try_apply_commits() {
+        let mut png = minimal_png();
+        png.try_apply(|png| {
+            png.insert_before_iend(chunk_from_strings("TeSt", "Message").unwrap());
+            png.rename_chunk_type("TeSt", "ruSt")?;
+            Ok(())
+        })
+        .unwrap();
+        assert!(png.chunk_by_type("ruSt").is_some());
+    }
+
+    #[test]
+    fn test_try_apply_rolls_back() {
+        let mut png = minimal_png();
+        let original = png.as_bytes();
+
+        let result = png.try_apply(|png| {
+            png.insert_before_iend(chunk_from_strings("TeSt", "Message").unwrap());
+            png.strip_color_profile();
+            png.remove_first_chunk_by_type("NoPe")?;
+            png.insert_before_iend(chunk_from_strings("TeSt", "Never added").unwrap());
+            Ok(())
+        });
+
+        assert!(result.is_err());
+        assert_eq!(png.as_bytes(), original);
+    }
+
+    #[test]
+    fn test_all_text_entries_keeps_duplicates() {
+        let mut png = minimal_png();
+        let entries = [
+            TextChunk::new_text("Comment", "First").unwrap(),
+            TextChunk::new_compressed("Author", "Lemon").unwrap(),
+            TextChunk::new_international("Comment", "Второй", true).unwrap(),
+        ];
+        for entry in entries.iter() {
+            png.insert_before_iend(entry.to_chunk());
+        }
+        // Not decodable, has no keyword separator
+        png.insert_before_iend(chunk_from_strings("tEXt", "Broken").unwrap());
+
+        assert_eq!(
+            png.all_text_entries(),
+            vec![
+                ("Comment".to_string(), "First".to_string()),
+                ("Author".to_string(), "Lemon".to_string()),
+                ("Comment".to_string(), "Второй".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chunk_flag_summary() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("tEXt", "Comment\0Public").unwrap());
+        png.insert_before_iend(chunk_from_strings("ruSt", "Private").unwrap());
+
+        assert_eq!(png.chunk_flag_summary(), (3, 1, 1));
+        assert_eq!(Png::new().chunk_flag_summary(), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_find_chunks_containing() {
+        let mut png = minimal_png();
+        let zip = [b'P', b'K', 3, 4, 20, 0, 0, 0];
+        png.insert_before_iend(Chunk::new_binary(
+            ChunkType::from_str("ruSt").unwrap(),
+            &[&[1, 2, 3][..], &zip].concat(),
+        ));
+
+        assert_eq!(png.find_chunks_containing(&[b'P', b'K', 3, 4]), vec![2]);
+        assert!(png.find_chunks_containing(b"%PDF").is_empty());
+        assert!(png.find_chunks_containing(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_as_ref_chunks() {
+        fn total_length(chunks: impl AsRef<[Chunk]>) -> u32 {
+            chunks.as_ref().iter().map(|chunk| chunk.length()).sum()
+        }
+
+        let png = testing_png();
+        assert_eq!(total_length(&png), 57);
+        assert_eq!(total_length(png.chunks()), 57);
+    }
+
+    #[test]
+    fn test_retain_chunks() {
+        let mut png = testing_png();
+        png.retain_chunks(|chunk| chunk.length() < 20);
+        assert_eq!(png.chunks().len(), 2);
+        assert!(png.chunk_by_type("FrSt").is_none());
+    }
+
+    #[test]
+    fn test_normalize_text_encoding() {
+        let mut png = minimal_png();
+        png.insert_before_iend(
+            TextChunk::new_text("Author", "Renée Müller")
+                .unwrap()
+                .to_chunk(),
+        );
+        png.insert_before_iend(
+            TextChunk::new_compressed("Comment", "Déjà vu")
+                .unwrap()
+                .to_chunk(),
+        );
+        png.insert_before_iend(
+            TextChunk::new_international("Title", "Ünïcödé", false)
+                .unwrap()
+                .to_chunk(),
+        );
+        let entries = png.all_text_entries();
+
+        png.normalize_text_encoding();
+
+        let texts = png.chunks_by_type("iTXt");
+        assert_eq!(texts.len(), 3);
+        assert!(png.chunk_by_type("tEXt").is_none());
+        assert!(png.chunk_by_type("zTXt").is_none());
+        assert_eq!(png.all_text_entries(), entries);
+        // The accented characters are now stored as UTF-8
+        assert!(texts[0].data().ends_with("Renée Müller".as_bytes()));
+        assert_eq!(&png.last_chunk().unwrap().chunk_type().to_string(), "IEND");
+    }
+
+    #[test]
+    fn test_take_chunk() {
+        let mut png = testing_png();
+        let taken = png.take_chunk(1).unwrap();
+        assert_eq!(&taken.chunk_type().to_string(), "miDl");
+
+        let types: Vec<String> = png
+            .chunks()
+            .iter()
+            .map(|chunk| chunk.chunk_type().to_string())
+            .collect();
+        assert_eq!(types, ["FrSt", "LASt"]);
+        assert!(png.take_chunk(2).is_none());
+    }
+
+    #[test]
+    fn test_split_at_iend() {
+        let mut png = minimal_png();
+        png.chunks
+            .push(chunk_from_strings("TeSt", "Trailing").unwrap());
+
+        let (body, iend, trailer) = png.split_at_iend();
+        assert_eq!(body.len(), 2);
+        assert_eq!(&iend.unwrap().chunk_type().to_string(), "IEND");
+        assert_eq!(trailer.len(), 1);
+        assert_eq!(&trailer[0].chunk_type().to_string(), "TeSt");
+
+        let png = testing_png();
+        let (body, iend, trailer) = png.split_at_iend();
+        assert_eq!(body.len(), 3);
+        assert!(iend.is_none());
+        assert!(trailer.is_empty());
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut png = Png::with_capacity(64);
+        assert!(png.chunks.capacity() >= 64);
+        assert!(png.chunks().is_empty());
+
+        for _ in 0..64 {
+            png.append_chunk(idat_chunk());
+        }
+        assert_eq!(png.chunks().len(), 64);
+    }
+
+    #[test]
+    fn test_parse_lenient_reserved_bit_warning() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("Rust", "Future").unwrap());
+        let bytes = png.as_bytes();
+        assert!(Png::try_from(bytes.as_slice()).is_err());
+
+        let (parsed, warnings) = Png::parse_lenient(&bytes).unwrap();
+        assert_eq!(parsed.chunks().len(), 4);
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            &warnings[0],
+            ParseWarning::ReservedBit { index: 2, chunk_type } if chunk_type.to_string() == "Rust"
+        ));
+
+        let (_, warnings) = Png::parse_lenient(&minimal_png().as_bytes()).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_chunks_mut() {
+        let mut png = testing_png();
+        for chunk in png.chunks_mut() {
+            let reversed: Vec<u8> = chunk.data().iter().rev().copied().collect();
+            chunk.set_data(reversed);
+        }
+        assert_eq!(png.chunks()[0].data(), b"knuhc tsrif eht ma I");
+
+        let reparsed = Png::from_chunks_bytes(&png.as_bytes()[8..]).unwrap();
+        assert_eq!(reparsed.chunks()[2].data(), b"knuhc tsal eht ma I");
+    }
+
+    #[test]
+    fn test_png_from_vec() {
+        let from_slice = Png::try_from(&PNG_FILE[..]).unwrap();
+        let from_vec = Png::try_from(PNG_FILE.to_vec()).unwrap();
+        assert!(from_vec.chunks() == from_slice.chunks());
+        assert_eq!(from_vec.as_bytes(), PNG_FILE.to_vec());
+
+        assert!(Png::try_from(PNG_FILE[..100].to_vec()).is_err());
+        assert!(Png::try_from(PNG_FILE[1..].to_vec()).is_err());
+        let mut corrupt = PNG_FILE.to_vec();
+        corrupt[8 + 4 + 4 + 13] ^= 0xFF;
+        assert!(Png::try_from(corrupt).is_err());
+    }
+
+    #[test]
+    fn test_png_from_vec_moves_image_data() {
+        let bytes = PNG_FILE.to_vec();
+        let buffer = bytes.as_ptr();
+        let png = Png::try_from(bytes).unwrap();
+        // The IDAT now owns what was the file's buffer
+        assert_eq!(png.chunk_by_type("IDAT").unwrap().data().as_ptr(), buffer);
+    }
+
+    #[test]
+    fn test_idat_decompressed() {
+        let png = minimal_png();
+        assert_eq!(png.idat_data(), idat_chunk().data());
+        // Filter type None followed by one opaque red pixel
+        assert_eq!(png.idat_decompressed().unwrap(), vec![0, 255, 0, 0, 255]);
+        assert!(png.idat_decompressed_with_limit(4).is_err());
+    }
+
+    #[test]
+    fn test_idat_data_spans_chunks() {
+        let mut png = minimal_png();
+        let data = png.take_chunk(1).unwrap().data().to_vec();
+        let (first, second) = data.split_at(5);
+        png.insert_before_iend(Chunk::new_binary(
+            ChunkType::from_str("IDAT").unwrap(),
+            first,
+        ));
+        png.insert_before_iend(Chunk::new_binary(
+            ChunkType::from_str("IDAT").unwrap(),
+            second,
+        ));
+
+        assert_eq!(png.idat_data(), data);
+        assert_eq!(png.idat_decompressed().unwrap(), vec![0, 255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_to_raw_pixels() {
+        assert_eq!(minimal_png().to_raw_pixels().unwrap(), vec![255, 0, 0, 255]);
+
+        // 2x2 8-bit RGB, the first line Sub filtered and the second Paeth filtered
+        let ihdr = Chunk::new(
+            ChunkType::from_str("IHDR").unwrap(),
+            vec![0, 0, 0, 2, 0, 0, 0, 2, 8, 2, 0, 0, 0],
+        );
+        let filtered = [1, 10, 20, 30, 30, 30, 30, 4, 5, 5, 5, 10, 20, 30];
+        let idat = Chunk::new(
+            ChunkType::from_str("IDAT").unwrap(),
+            compression::deflate(&filtered),
+        );
+        let png = Png::from_chunks(vec![ihdr, idat, Chunk::iend()]);
+        assert_eq!(
+            png.to_raw_pixels().unwrap(),
+            vec![10, 20, 30, 40, 50, 60, 15, 25, 35, 50, 70, 90]
+        );
+    }
+
+    #[test]
+    fn test_to_raw_pixels_huge_dimensions() {
+        // The largest dimensions the spec allows, far more pixels than fit in memory
+        let max = i32::MAX as u32;
+        let ihdr = Ihdr::new(max, max, 16, crate::ihdr::ColorType::Rgba).unwrap();
+        let idat = Chunk::new(ChunkType::IDAT, compression::deflate(&[0; 16]));
+        let png = Png::from_chunks(vec![ihdr.to_chunk(), idat, Chunk::iend()]);
+
+        let error = png.to_raw_pixels().err().unwrap();
+        assert!(error.to_string().contains("Image too large"));
+    }
+
+    #[test]
+    fn test_icc_profile() {
+        let mut png = minimal_png();
+        assert!(png.icc_profile().unwrap().is_none());
+
+        let mut data = b"bomb\0\0".to_vec();
+        data.extend_from_slice(&compression::deflate(&vec![0; 1024 * 1024]));
+        png.insert_before_iend(Chunk::new(ChunkType::from_str("iCCP").unwrap(), data));
+        assert_eq!(
+            png.icc_profile().unwrap().unwrap().profile().len(),
+            1024 * 1024
+        );
+        assert!(png.icc_profile_with_limit(64 * 1024).is_err());
+    }
+
+    #[test]
+    fn test_xmp() {
+        let mut png = minimal_png();
+        assert!(png.xmp().is_none());
+
+        let packet = "<x:xmpmeta xmlns:x=\"adobe:ns:meta/\"></x:xmpmeta>";
+        png.insert_before_iend(
+            TextChunk::new_text("XML:com.adobe.xmp", "Not in iTXt")
+                .unwrap()
+                .to_chunk(),
+        );
+        png.insert_before_iend(
+            TextChunk::new_international("XML:com.adobe.xmp", packet, true)
+                .unwrap()
+                .to_chunk(),
+        );
+        assert_eq!(png.xmp().as_deref(), Some(packet));
+    }
+
+    #[test]
+    fn test_statistics() {
+        let stats = minimal_png().statistics();
+        assert_eq!(stats.chunk_count, 3);
+        assert_eq!(stats.total_size, 8 + 3 * 12 + 13 + 13);
+        assert_eq!(stats.dimensions, Some((1, 1)));
+        assert!(!stats.has_text);
+        assert!(!stats.has_exif);
+        assert!(!stats.is_animated);
+        assert!(!stats.has_color_profile);
+        assert_eq!(
+            stats.per_type,
+            vec![
+                ChunkTypeStats {
+                    chunk_type: "IHDR".to_string(),
+                    count: 1,
+                    size: 13
+                },
+                ChunkTypeStats {
+                    chunk_type: "IDAT".to_string(),
+                    count: 1,
+                    size: 13
+                },
+                ChunkTypeStats {
+                    chunk_type: "IEND".to_string(),
+                    count: 1,
+                    size: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_statistics_flags() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("tEXt", "Author\0Lemon").unwrap());
+        png.insert_before_iend(chunk_from_strings("tEXt", "Title\0Dice").unwrap());
+        png.insert_before_iend(Chunk::new(ChunkType::from_str("gAMA").unwrap(), vec![0; 4]));
+
+        let stats = png.statistics();
+        assert!(stats.has_text);
+        assert!(stats.has_color_profile);
+        let text = &stats.per_type[2];
+        assert_eq!(
+            (text.chunk_type.as_str(), text.count, text.size),
+            ("tEXt", 2, 22)
+        );
+    }
+
+    #[test]
+    fn test_dedup_identical_chunks() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("tEXt", "Author\0Lemon").unwrap());
+        png.insert_before_iend(chunk_from_strings("tEXt", "Title\0Dice").unwrap());
+        png.insert_before_iend(chunk_from_strings("tEXt", "Author\0Lemon").unwrap());
+        png.insert_before_iend(idat_chunk());
+
+        assert_eq!(png.dedup_identical_chunks(), 1);
+        let texts: Vec<&[u8]> = png
+            .chunks_by_type("tEXt")
+            .into_iter()
+            .map(|chunk| chunk.data())
+            .collect();
+        assert_eq!(texts, [&b"Author\0Lemon"[..], b"Title\0Dice"]);
+        assert_eq!(png.chunks_by_type("IDAT").len(), 2);
+        assert_eq!(png.dedup_identical_chunks(), 0);
+    }
+
+    #[test]
+    fn test_to_base64_data_url() {
+        let png = minimal_png();
+        let url = png.to_base64_data_url();
+        let encoded = url.strip_prefix("data:image/png;base64,").unwrap();
+        assert_eq!(STANDARD.decode(encoded).unwrap(), png.as_bytes());
+    }
+
+    #[test]
+    fn test_custom_signature_round_trip() {
+        let signature = *b"\x89MNG\r\n\x1a\n";
+        let png = Png::with_signature(signature, testing_chunks());
+        let bytes = png.as_bytes();
+        assert_eq!(&bytes[..8], &signature);
+
+        let parsed = Png::parse_with_signature(&bytes, signature).unwrap();
+        assert_eq!(parsed.signature(), &signature);
+        assert_eq!(parsed.as_bytes(), bytes);
+        assert!(Png::try_from(bytes.as_slice()).is_err());
+        assert_eq!(minimal_png().signature(), &Png::STANDARD_HEADER);
+    }
+
+    #[test]
+    fn test_validate_signature_strict() {
+        assert!(Png::validate_signature_strict(&PNG_FILE).is_ok());
+
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, 0x4A, 0x46, 0x49, 0x46];
+        let error = Png::validate_signature_strict(&jpeg).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<PngError>(),
+            Some(PngError::SignatureMismatch(differing, Some(_))) if differing.len() == 8
+        ));
+        assert!(error.to_string().contains("JPEG"));
+    }
+
+    #[test]
+    fn test_validate_signature_strict_line_endings() {
+        // CR-LF converted to LF, shifting the rest of the signature down a byte
+        let mangled: Vec<u8> = PNG_FILE
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 4)
+            .map(|(_, &b)| b)
+            .collect();
+        let error = Png::validate_signature_strict(&mangled).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<PngError>(),
+            Some(PngError::SignatureMismatch(differing, Some(_))) if differing == &[4, 5, 6, 7]
+        ));
+        assert!(error.to_string().contains("line-ending converted"));
+
+        let mut doubled = PNG_FILE.to_vec();
+        doubled[4] = b'\n';
+        let error = Png::validate_signature_strict(&doubled).unwrap_err();
+        assert!(error.to_string().contains("bytes 4-5 are LF-LF"));
+    }
+
+    #[test]
+    fn test_chunks_after_iend() {
+        let mut png = minimal_png();
+        assert_eq!(png.chunks_after_iend(), 0);
+
+        png.chunks
+            .push(chunk_from_strings("TeSt", "Hidden").unwrap());
+        png.chunks.push(Chunk::iend());
+        assert_eq!(png.chunks_after_iend(), 2);
+        assert_eq!(testing_png().chunks_after_iend(), 0);
+    }
+
+    #[test]
+    fn test_index_and_get() {
+        let png = testing_png();
+        assert_eq!(&png[1].chunk_type().to_string(), "miDl");
+        assert_eq!(png.get(2), Some(&png[2]));
+        assert_eq!(png.get(3), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_range() {
+        let png = testing_png();
+        let _ = &png[3];
+    }
+
+    #[test]
+    fn test_data_checksums() {
+        let mut png = testing_png();
+        let before = png.data_checksums();
+        assert_eq!(before.len(), png.chunks().len());
+        assert_eq!(before[0], ("FrSt".to_string(), png.chunks()[0].crc()));
+
+        png.chunks_mut()[1].set_data(b"Edited".to_vec());
+        let after = png.data_checksums();
+        let changed: Vec<usize> = (0..before.len())
+            .filter(|&i| before[i] != after[i])
+            .collect();
+        assert_eq!(changed, vec![1]);
+    }
+
+    #[test]
+    fn test_into_chunks() {
+        let chunks = testing_png().into_chunks();
+        assert_eq!(chunks, testing_chunks());
+    }
+
+    #[test]
+    fn test_app_metadata_round_trip() {
+        let mut png = minimal_png();
+        png.set_app_metadata("meTa", 1, b"first").unwrap();
+        png.set_app_metadata("meTa", 2, b"second").unwrap();
+
+        assert_eq!(png.chunks_by_type("meTa").len(), 1);
+        assert!(png.last_chunk().unwrap().is_type("IEND"));
+        assert_eq!(png.app_metadata("meTa"), Some((2, b"second".to_vec())));
+        assert_eq!(png.app_metadata("abCd"), None);
+
+        let png = Png::try_from(png.as_bytes().as_ref()).unwrap();
+        assert_eq!(png.app_metadata("meTa"), Some((2, b"second".to_vec())));
+    }
+
+    #[test]
+    fn test_app_metadata_invalid_app_id() {
+        let mut png = minimal_png();
+        for app_id in ["MeTa", "mETa", "meta", "toolong"] {
+            assert!(png.set_app_metadata(app_id, 1, b"").is_err());
+        }
+        let error = png.set_app_metadata("MeTa", 1, b"").unwrap_err();
+        assert_eq!(problem_kind(&error), "invalid_app_id");
+    }
+
+    #[test]
+    fn test_byte_offset_of() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+        assert_eq!(png.byte_offset_of(0), Some(8));
+        for index in 0..png.chunks().len() {
+            let offset = png.byte_offset_of(index).unwrap();
+            let (chunk, _) = Chunk::parse_at(&bytes, offset).unwrap();
+            assert_eq!(chunk, png[index]);
+        }
+        assert_eq!(png.byte_offset_of(png.chunks().len()), None);
+    }
+
+    #[test]
+    fn test_has_private_chunks() {
+        let mut png = minimal_png();
+        assert!(!png.has_private_chunks());
+        assert!(png.assert_no_private_chunks().is_ok());
+
+        png.insert_before_iend(chunk_from_strings("tEXt", "Public").unwrap());
+        assert!(!png.has_private_chunks());
+
+        png.insert_before_iend(chunk_from_strings("ruSt", "Private").unwrap());
+        assert!(png.has_private_chunks());
+        let error = png.assert_no_private_chunks().unwrap_err();
+        assert_eq!(problem_kind(&error), "private_chunk");
+        assert_eq!(error.to_string(), "Private ancillary chunks found: ruSt");
+    }
+
+    #[test]
+    fn test_private_critical_chunk_isnt_flagged() {
+        let mut png = minimal_png();
+        png.insert_before_iend(chunk_from_strings("RuSt", "Private critical").unwrap());
+        assert!(!png.has_private_chunks());
+        assert!(png.assert_no_private_chunks().is_ok());
+    }
+
+    #[test]
+    fn test_equal_ignoring_crc() {
+        let original = Png::try_from(&PNG_FILE[..]).unwrap();
+        let mut bytes = PNG_FILE.to_vec();
+        // Corrupt the IHDR crc
+        bytes[8 + 4 + 4 + 13] ^= 0xFF;
+        let corrupted = Png::try_from_unchecked(&bytes).unwrap();
+        assert!(corrupted.equal_ignoring_crc(&original));
+
+        let repaired = Png::try_from(corrupted.as_bytes().as_ref()).unwrap();
+        assert!(repaired.equal_ignoring_crc(&original));
+        assert_eq!(repaired.as_bytes(), PNG_FILE.to_vec());
+
+        let mut edited = repaired.clone();
+        edited.chunks_mut()[0].data_mut()[0] ^= 0xFF;
+        assert!(!edited.equal_ignoring_crc(&original));
+        let resigned = Png::with_signature([0; 8], original.chunks().to_vec());
+        assert!(!resigned.equal_ignoring_crc(&original));
+    }
+
     #[test]
     fn test_png_trait_impls() {
         let chunk_bytes: Vec<u8> = testing_chunks()
@@ -0,0 +1,132 @@
+use std::io::{BufReader, Read};
+
+use crate::chunk::{is_end_of_stream, Chunk};
+use crate::Error;
+
+/// Iterates over the chunks of a stream, e.g. a file after its 8 byte signature.
+/// The input is wrapped in a [`BufReader`] so that the small length/type/crc reads don't each
+/// hit the underlying source. Iteration ends cleanly at EOF between chunks and stops after the
+/// first error.
+pub struct ChunkReader<R: Read> {
+    reader: BufReader<R>,
+    max_chunk_len: usize,
+    done: bool,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(inner: R) -> ChunkReader<R> {
+        ChunkReader::with_limit(inner, usize::MAX)
+    }
+
+    /// Yields an error instead of allocating for any chunk that declares more than
+    /// `max_chunk_len` bytes of data, for reading untrusted streams.
+    pub fn with_limit(inner: R, max_chunk_len: usize) -> ChunkReader<R> {
+        ChunkReader {
+            reader: BufReader::new(inner),
+            max_chunk_len,
+            done: false,
+        }
+    }
+
+    fn read_chunk(&mut self) -> Result<Option<Chunk>, Error> {
+        match Chunk::from_reader_with_limit(&mut self.reader, self.max_chunk_len) {
+            Ok(chunk) => Ok(Some(chunk)),
+            Err(e) if is_end_of_stream(&e) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.read_chunk().transpose();
+        if !matches!(item, Some(Ok(_))) {
+            self.done = true;
+        }
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DICE: &[u8] = include_bytes!("../tests/fixtures/dice.png");
+
+    /// Counts how often the wrapped reader is asked for bytes.
+    struct CountingReader<'a> {
+        inner: &'a [u8],
+        reads: usize,
+    }
+
+    impl Read for &mut CountingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.reads += 1;
+            self.inner.read(buf)
+        }
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_all_chunks() {
+        let types: Vec<String> = ChunkReader::new(&DICE[8..])
+            .map(|chunk| chunk.unwrap().chunk_type().to_string())
+            .collect();
+        assert_eq!(
+            types,
+            ["IHDR", "sRGB", "gAMA", "pHYs", "IDAT", "RuSt", "IEND"]
+        );
+    }
+
+    #[test]
+    fn test_chunk_reader_buffers_reads() {
+        let mut source = CountingReader {
+            inner: &DICE[8..],
+            reads: 0,
+        };
+        let chunks: Vec<Chunk> = ChunkReader::new(&mut source)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(chunks.len(), 7);
+        // 4 reads per chunk without buffering, the whole file fits in one buffer fill plus the
+        // read at EOF
+        assert!(source.reads <= 3, "{} reads", source.reads);
+    }
+
+    #[test]
+    fn test_chunk_reader_limit() {
+        let types: Vec<String> = ChunkReader::with_limit(&DICE[8..], 13)
+            .map(|chunk| chunk.unwrap().chunk_type().to_string())
+            .take(4)
+            .collect();
+        assert_eq!(types, ["IHDR", "sRGB", "gAMA", "pHYs"]);
+
+        // The IDAT is far bigger than the limit
+        let mut reader = ChunkReader::with_limit(&DICE[8..], 13).skip(4);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_oversized_length() {
+        // Claims 4 GB of data
+        let mut bytes = u32::MAX.to_be_bytes().to_vec();
+        bytes.extend_from_slice(b"RuSt");
+        let mut reader = ChunkReader::with_limit(bytes.as_slice(), 1024 * 1024);
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_stops_at_error() {
+        let mut bytes = DICE[8..].to_vec();
+        // Corrupt the IHDR crc
+        bytes[4 + 4 + 13] ^= 0xFF;
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+/// Summary of a PNG for a quick overview, see [`crate::png::Png::statistics`].
+#[derive(PartialEq, Eq, Debug)]
+pub struct PngStats {
+    pub chunk_count: usize,
+    /// Size of the whole serialized file, signature included.
+    pub total_size: usize,
+    /// Per chunk type counts in order of first appearance.
+    pub per_type: Vec<ChunkTypeStats>,
+    pub has_text: bool,
+    pub has_exif: bool,
+    pub is_animated: bool,
+    pub has_color_profile: bool,
+    /// Width and height, if IHDR parses.
+    pub dimensions: Option<(u32, u32)>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct ChunkTypeStats {
+    pub chunk_type: String,
+    pub count: usize,
+    /// Data bytes over all chunks of this type.
+    pub size: usize,
+}
+
+impl Display for PngStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let yes_no = |flag: bool| if flag { "yes" } else { "no" };
+        writeln!(f, "Chunks : {}", self.chunk_count)?;
+        writeln!(f, "Size : {} bytes", self.total_size)?;
+        match self.dimensions {
+            Some((width, height)) => writeln!(f, "Dimensions : {}x{}", width, height)?,
+            None => writeln!(f, "Dimensions : unknown")?,
+        }
+        writeln!(f, "Text : {}", yes_no(self.has_text))?;
+        writeln!(f, "Exif : {}", yes_no(self.has_exif))?;
+        writeln!(f, "Animated : {}", yes_no(self.is_animated))?;
+        writeln!(f, "Color profile : {}", yes_no(self.has_color_profile))?;
+        for entry in self.per_type.iter() {
+            writeln!(
+                f,
+                "{} : {} chunk(s), {} bytes",
+                entry.chunk_type, entry.count, entry.size
+            )?;
+        }
+        Ok(())
+    }
+}
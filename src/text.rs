@@ -0,0 +1,437 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::compression::{self, DEFAULT_DECOMPRESSION_LIMIT};
+use crate::Error;
+
+/// Which of the three textual chunk types a [`TextChunk`] is stored as.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TextKind {
+    /// tEXt, uncompressed latin-1
+    Text,
+    /// zTXt, compressed latin-1
+    CompressedText,
+    /// iTXt, UTF-8 and optionally compressed
+    InternationalText,
+}
+
+impl TextKind {
+    fn chunk_type_bytes(&self) -> [u8; 4] {
+        match self {
+            TextKind::Text => *b"tEXt",
+            TextKind::CompressedText => *b"zTXt",
+            TextKind::InternationalText => *b"iTXt",
+        }
+    }
+}
+
+/// How closely keywords are checked against the spec when building a [`TextChunk`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum KeywordPolicy {
+    /// Printable latin-1 without leading, trailing or consecutive spaces.
+    #[default]
+    Strict,
+    /// Any latin-1 except null, for keywords copied from files that bend the rules.
+    Lenient,
+}
+
+/// Keyword and text of a tEXt, zTXt or iTXt chunk, decoded and decompressed.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TextChunk {
+    kind: TextKind,
+    keyword: String,
+    text: String,
+    // iTXt only
+    compressed: bool,
+    language_tag: String,
+    translated_keyword: String,
+}
+
+impl TextChunk {
+    const MAX_KEYWORD_LENGTH: usize = 79;
+    const COMPRESSION_METHOD: u8 = 0;
+
+    /// Builds a tEXt chunk, both keyword and text must be latin-1.
+    pub fn new_text(keyword: &str, text: &str) -> Result<TextChunk, Error> {
+        TextChunk::new_text_with_policy(keyword, text, KeywordPolicy::Strict)
+    }
+
+    pub fn new_text_with_policy(
+        keyword: &str,
+        text: &str,
+        policy: KeywordPolicy,
+    ) -> Result<TextChunk, Error> {
+        TextChunk::new_latin1(TextKind::Text, keyword, text, policy)
+    }
+
+    /// Builds a zTXt chunk, both keyword and text must be latin-1.
+    pub fn new_compressed(keyword: &str, text: &str) -> Result<TextChunk, Error> {
+        TextChunk::new_compressed_with_policy(keyword, text, KeywordPolicy::Strict)
+    }
+
+    pub fn new_compressed_with_policy(
+        keyword: &str,
+        text: &str,
+        policy: KeywordPolicy,
+    ) -> Result<TextChunk, Error> {
+        TextChunk::new_latin1(TextKind::CompressedText, keyword, text, policy)
+    }
+
+    /// Builds an iTXt chunk, the keyword must be latin-1 but the text can be any UTF-8.
+    pub fn new_international(
+        keyword: &str,
+        text: &str,
+        compressed: bool,
+    ) -> Result<TextChunk, Error> {
+        TextChunk::new_international_with_policy(keyword, text, compressed, KeywordPolicy::Strict)
+    }
+
+    pub fn new_international_with_policy(
+        keyword: &str,
+        text: &str,
+        compressed: bool,
+        policy: KeywordPolicy,
+    ) -> Result<TextChunk, Error> {
+        validate_keyword(keyword, policy)?;
+        Ok(TextChunk {
+            kind: TextKind::InternationalText,
+            keyword: keyword.to_string(),
+            text: text.to_string(),
+            compressed,
+            language_tag: String::new(),
+            translated_keyword: String::new(),
+        })
+    }
+
+    fn new_latin1(
+        kind: TextKind,
+        keyword: &str,
+        text: &str,
+        policy: KeywordPolicy,
+    ) -> Result<TextChunk, Error> {
+        validate_keyword(keyword, policy)?;
+        if !is_latin1(text) {
+            return Err(Box::new(TextError::NotLatin1));
+        }
+        Ok(TextChunk {
+            kind,
+            keyword: keyword.to_string(),
+            text: text.to_string(),
+            compressed: kind == TextKind::CompressedText,
+            language_tag: String::new(),
+            translated_keyword: String::new(),
+        })
+    }
+
+    pub fn kind(&self) -> TextKind {
+        self.kind
+    }
+    pub fn keyword(&self) -> &str {
+        &self.keyword
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+    /// Empty unless this is an iTXt chunk that sets one.
+    pub fn language_tag(&self) -> &str {
+        &self.language_tag
+    }
+    /// Empty unless this is an iTXt chunk that sets one.
+    pub fn translated_keyword(&self) -> &str {
+        &self.translated_keyword
+    }
+
+    /// Same keyword and text stored as iTXt, zTXt stays compressed.
+    pub fn to_international(&self) -> TextChunk {
+        TextChunk {
+            kind: TextKind::InternationalText,
+            ..self.clone()
+        }
+    }
+
+    /// Whether the chunk type is one of tEXt, zTXt or iTXt.
+    pub fn is_text_chunk(chunk: &Chunk) -> bool {
+        matches!(&chunk.chunk_type().bytes(), b"tEXt" | b"zTXt" | b"iTXt")
+    }
+
+    /// Decodes a textual chunk, refusing to decompress more than `limit` bytes of text.
+    pub fn from_chunk_with_limit(chunk: &Chunk, limit: usize) -> Result<TextChunk, Error> {
+        let kind = match &chunk.chunk_type().bytes() {
+            b"tEXt" => TextKind::Text,
+            b"zTXt" => TextKind::CompressedText,
+            b"iTXt" => TextKind::InternationalText,
+            _ => return Err(Box::new(TextError::WrongChunkType)),
+        };
+        let (keyword, rest) = split_null(chunk.data())?;
+        let keyword = decode_latin1(keyword);
+
+        match kind {
+            TextKind::Text => Ok(TextChunk {
+                kind,
+                keyword,
+                text: decode_latin1(rest),
+                compressed: false,
+                language_tag: String::new(),
+                translated_keyword: String::new(),
+            }),
+            TextKind::CompressedText => {
+                let (&method, compressed) = rest.split_first().ok_or(TextError::Truncated)?;
+                check_compression_method(method)?;
+                Ok(TextChunk {
+                    kind,
+                    keyword,
+                    text: decode_latin1(&compression::inflate(compressed, limit)?),
+                    compressed: true,
+                    language_tag: String::new(),
+                    translated_keyword: String::new(),
+                })
+            }
+            TextKind::InternationalText => {
+                if rest.len() < 2 {
+                    return Err(Box::new(TextError::Truncated));
+                }
+                let compressed = rest[0] != 0;
+                check_compression_method(rest[1])?;
+                let (language_tag, rest) = split_null(&rest[2..])?;
+                let (translated_keyword, text) = split_null(rest)?;
+
+                let text = match compressed {
+                    true => compression::inflate(text, limit)?,
+                    false => text.to_vec(),
+                };
+                Ok(TextChunk {
+                    kind,
+                    keyword,
+                    text: String::from_utf8(text)?,
+                    compressed,
+                    language_tag: String::from_utf8(language_tag.to_vec())?,
+                    translated_keyword: String::from_utf8(translated_keyword.to_vec())?,
+                })
+            }
+        }
+    }
+
+    /// Encodes back into a tEXt, zTXt or iTXt chunk, compressing the text if needed.
+    pub fn to_chunk(&self) -> Chunk {
+        let mut data = encode_latin1(&self.keyword);
+        data.push(0);
+        match self.kind {
+            TextKind::Text => data.extend(encode_latin1(&self.text)),
+            TextKind::CompressedText => {
+                data.push(TextChunk::COMPRESSION_METHOD);
+                data.extend(compression::deflate(&encode_latin1(&self.text)));
+            }
+            TextKind::InternationalText => {
+                data.push(self.compressed as u8);
+                data.push(TextChunk::COMPRESSION_METHOD);
+                data.extend(self.language_tag.as_bytes());
+                data.push(0);
+                data.extend(self.translated_keyword.as_bytes());
+                data.push(0);
+                match self.compressed {
+                    true => data.extend(compression::deflate(self.text.as_bytes())),
+                    false => data.extend(self.text.as_bytes()),
+                }
+            }
+        }
+        let chunk_type = ChunkType::try_from(self.kind.chunk_type_bytes()).unwrap();
+        Chunk::new(chunk_type, data)
+    }
+}
+
+impl TryFrom<&Chunk> for TextChunk {
+    type Error = Error;
+
+    /// Decodes with the [`DEFAULT_DECOMPRESSION_LIMIT`].
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        TextChunk::from_chunk_with_limit(chunk, DEFAULT_DECOMPRESSION_LIMIT)
+    }
+}
+
+/// Keywords are 1-79 latin-1 characters, [`KeywordPolicy::Strict`] also wants them printable
+/// and without leading, trailing or consecutive spaces.
+fn validate_keyword(keyword: &str, policy: KeywordPolicy) -> Result<(), Error> {
+    let well_formed = match policy {
+        KeywordPolicy::Strict => {
+            keyword
+                .chars()
+                .all(|c| matches!(c as u32, 32..=126 | 161..=255))
+                && !keyword.starts_with(' ')
+                && !keyword.ends_with(' ')
+                && !keyword.contains("  ")
+        }
+        // The null byte separates the keyword from the text so it can never be allowed
+        KeywordPolicy::Lenient => keyword.chars().all(|c| matches!(c as u32, 1..=255)),
+    };
+    if keyword.is_empty() || keyword.chars().count() > TextChunk::MAX_KEYWORD_LENGTH || !well_formed
+    {
+        return Err(Box::new(TextError::InvalidKeyword(keyword.to_string())));
+    }
+    Ok(())
+}
+
+fn check_compression_method(method: u8) -> Result<(), Error> {
+    match method {
+        TextChunk::COMPRESSION_METHOD => Ok(()),
+        _ => Err(Box::new(TextError::UnknownCompressionMethod(method))),
+    }
+}
+
+/// Splits at the first null byte, dropping the null.
+fn split_null(data: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    match data.iter().position(|&b| b == 0) {
+        Some(ind) => Ok((&data[..ind], &data[ind + 1..])),
+        None => Err(Box::new(TextError::MissingNullSeparator)),
+    }
+}
+
+fn is_latin1(s: &str) -> bool {
+    s.chars().all(|c| (c as u32) <= 0xFF)
+}
+
+/// Latin-1 maps every byte straight to the code point of the same value.
+fn decode_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+/// Callers make sure the string is latin-1 first.
+fn encode_latin1(s: &str) -> Vec<u8> {
+    s.chars().map(|c| c as u8).collect()
+}
+
+#[derive(Debug)]
+enum TextError {
+    WrongChunkType,
+    MissingNullSeparator,
+    Truncated,
+    UnknownCompressionMethod(u8),
+    InvalidKeyword(String),
+    NotLatin1,
+}
+
+impl std::error::Error for TextError {}
+
+impl Display for TextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextError::WrongChunkType => write!(f, "Chunk is not a tEXt, zTXt or iTXt chunk!"),
+            TextError::MissingNullSeparator => write!(f, "Missing null separator in text chunk!"),
+            TextError::Truncated => write!(f, "Text chunk is truncated!"),
+            TextError::UnknownCompressionMethod(method) => {
+                write!(f, "Unknown compression method {}", method)
+            }
+            TextError::InvalidKeyword(keyword) => write!(f, "Invalid keyword {:?}", keyword),
+            TextError::NotLatin1 => write!(f, "Text can't be encoded as latin-1!"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_text_round_trip() {
+        let text = TextChunk::new_text("Comment", "Café au lait").unwrap();
+        let chunk = text.to_chunk();
+        assert_eq!(&chunk.chunk_type().to_string(), "tEXt");
+        // é is a single latin-1 byte
+        assert_eq!(chunk.length() as usize, "Comment".len() + 1 + 12);
+        assert_eq!(TextChunk::try_from(&chunk).unwrap(), text);
+    }
+
+    #[test]
+    fn test_compressed_text_round_trip() {
+        let text = TextChunk::new_compressed("Comment", &"compress me ".repeat(100)).unwrap();
+        let chunk = text.to_chunk();
+        assert_eq!(&chunk.chunk_type().to_string(), "zTXt");
+        assert!((chunk.length() as usize) < 1200);
+        assert_eq!(TextChunk::try_from(&chunk).unwrap(), text);
+    }
+
+    #[test]
+    fn test_international_text_round_trip() {
+        let title = "日本語のタイトル";
+        for compressed in [false, true] {
+            let text = TextChunk::new_international("Title", title, compressed).unwrap();
+            let chunk = text.to_chunk();
+            assert_eq!(&chunk.chunk_type().to_string(), "iTXt");
+            assert_eq!(TextChunk::try_from(&chunk).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_to_international() {
+        let text = TextChunk::new_compressed("Comment", "Café").unwrap();
+        let international = text.to_international();
+        assert_eq!(international.kind(), TextKind::InternationalText);
+        assert_eq!(international.keyword(), "Comment");
+        assert_eq!(international.text(), "Café");
+        assert!(international.is_compressed());
+    }
+
+    #[test]
+    fn test_text_rejects_non_latin1() {
+        assert!(TextChunk::new_text("Title", "日本語").is_err());
+        assert!(TextChunk::new_international("日本語", "Title", false).is_err());
+    }
+
+    #[test]
+    fn test_invalid_keywords() {
+        assert!(TextChunk::new_text("", "text").is_err());
+        assert!(TextChunk::new_text(" Comment", "text").is_err());
+        assert!(TextChunk::new_text("Comment ", "text").is_err());
+        assert!(TextChunk::new_text("My  Comment", "text").is_err());
+        assert!(TextChunk::new_text(&"a".repeat(80), "text").is_err());
+        assert!(TextChunk::new_text("My Comment", "text").is_ok());
+    }
+
+    #[test]
+    fn test_keyword_policy() {
+        let strict = TextChunk::new_text_with_policy("Comment ", "text", KeywordPolicy::Strict);
+        let error = strict.err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<TextError>(),
+            Some(TextError::InvalidKeyword(_))
+        ));
+
+        let lenient =
+            TextChunk::new_text_with_policy("Comment ", "text", KeywordPolicy::Lenient).unwrap();
+        assert_eq!(lenient.keyword(), "Comment ");
+        let chunk = lenient.to_chunk();
+        assert_eq!(TextChunk::try_from(&chunk).unwrap().keyword(), "Comment ");
+
+        // Still bounded by what the chunk layout can hold
+        for keyword in ["", "Com\0ment", "日本語"] {
+            assert!(
+                TextChunk::new_text_with_policy(keyword, "text", KeywordPolicy::Lenient).is_err()
+            );
+        }
+        assert_eq!(KeywordPolicy::default(), KeywordPolicy::Strict);
+    }
+
+    #[test]
+    fn test_missing_null_separator() {
+        let chunk = Chunk::new(ChunkType::from_str("tEXt").unwrap(), b"Comment".to_vec());
+        assert!(TextChunk::try_from(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_decompression_limit() {
+        let bomb = TextChunk::new_compressed("Comment", &"a".repeat(1024 * 1024))
+            .unwrap()
+            .to_chunk();
+        assert!(TextChunk::from_chunk_with_limit(&bomb, 64 * 1024).is_err());
+        assert!(TextChunk::try_from(&bomb).is_ok());
+
+        let bomb = TextChunk::new_international("Comment", &"a".repeat(1024 * 1024), true)
+            .unwrap()
+            .to_chunk();
+        assert!(TextChunk::from_chunk_with_limit(&bomb, 64 * 1024).is_err());
+    }
+}
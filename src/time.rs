@@ -0,0 +1,140 @@
+use std::fmt::Display;
+
+use crate::chunk::Chunk;
+use crate::Error;
+
+/// Parsed contents of the tIME chunk, the time of the last image modification in UTC.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Time {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl Time {
+    pub const LENGTH: usize = 7;
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+    /// Up to 60 to allow for leap seconds.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl TryFrom<&Chunk> for Time {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if !chunk.is_type("tIME") {
+            return Err(Box::new(TimeError::WrongChunkType));
+        }
+        let data = chunk.data();
+        if data.len() != Time::LENGTH {
+            return Err(Box::new(TimeError::LengthError(data.len())));
+        }
+
+        let time = Time {
+            year: u16::from_be_bytes([data[0], data[1]]),
+            month: data[2],
+            day: data[3],
+            hour: data[4],
+            minute: data[5],
+            second: data[6],
+        };
+        if !(1..=12).contains(&time.month)
+            || !(1..=31).contains(&time.day)
+            || time.hour > 23
+            || time.minute > 59
+            || time.second > 60
+        {
+            return Err(Box::new(TimeError::OutOfRange));
+        }
+        Ok(time)
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[derive(Debug)]
+enum TimeError {
+    WrongChunkType,
+    LengthError(usize),
+    OutOfRange,
+}
+
+impl std::error::Error for TimeError {}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeError::WrongChunkType => write!(f, "Chunk is not a tIME chunk!"),
+            TimeError::LengthError(got) => {
+                write!(
+                    f,
+                    "tIME Length Error! Expected {} bytes, Got {} bytes",
+                    Time::LENGTH,
+                    got
+                )
+            }
+            TimeError::OutOfRange => write!(f, "tIME field out of range!"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn time_chunk(data: Vec<u8>) -> Chunk {
+        Chunk::new(ChunkType::from_str("tIME").unwrap(), data)
+    }
+
+    #[test]
+    fn test_valid_time() {
+        let chunk = time_chunk(vec![7, 232, 2, 29, 13, 5, 9]);
+        let time = Time::try_from(&chunk).unwrap();
+        assert_eq!(time.year(), 2024);
+        assert_eq!(time.month(), 2);
+        assert_eq!(time.day(), 29);
+        assert_eq!(&time.to_string(), "2024-02-29 13:05:09 UTC");
+    }
+
+    #[test]
+    fn test_invalid_time_month() {
+        let chunk = time_chunk(vec![7, 232, 13, 29, 13, 5, 9]);
+        assert!(Time::try_from(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_invalid_time_length() {
+        let chunk = time_chunk(vec![7, 232, 2]);
+        assert!(Time::try_from(&chunk).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn fixture() -> Vec<u8> {
+    fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/dice.png"
+    ))
+    .unwrap()
+}
+
+/// Writes `bytes` to a file in the temp dir unique to the calling test.
+fn temp_png(name: &str, bytes: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pngmehd-{}-{}.png", std::process::id(), name));
+    fs::write(&path, bytes).unwrap();
+    path
+}
+
+/// Inserts a chunk with a freshly computed crc right before the trailing IEND.
+fn with_chunk(mut bytes: Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let iend = bytes.len() - 12;
+    let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+    chunk.extend_from_slice(chunk_type);
+    chunk.extend_from_slice(data);
+    let crc = crc::crc32::checksum_ieee(&chunk[4..]);
+    chunk.extend_from_slice(&crc.to_be_bytes());
+    bytes.splice(iend..iend, chunk);
+    bytes
+}
+
+fn pngme(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pngmehd"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout_json(output: &Output) -> serde_json::Value {
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_verify_json_valid() {
+    let path = temp_png("verify-json-valid", &fixture());
+    let output = pngme(&["verify", path.to_str().unwrap(), "--json"]);
+
+    assert!(output.status.success());
+    let report = stdout_json(&output);
+    assert_eq!(report["valid"], true);
+    assert_eq!(report["errors"].as_array().unwrap().len(), 0);
+    assert!(report["warnings"].is_array());
+}
+
+#[test]
+fn test_verify_json_crc_mismatch() {
+    let mut bytes = fixture();
+    // Corrupt the IHDR crc, right after the header, length, type and data
+    bytes[8 + 4 + 4 + 13] ^= 0xFF;
+    let path = temp_png("verify-json-crc", &bytes);
+    let output = pngme(&["verify", path.to_str().unwrap(), "--json"]);
+
+    assert!(!output.status.success());
+    let report = stdout_json(&output);
+    assert_eq!(report["valid"], false);
+    let errors = report["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["kind"], "crc_mismatch");
+    assert!(errors[0]["message"].is_string());
+}
+
+#[test]
+fn test_verify_no_private() {
+    let bytes = with_chunk(fixture(), b"ruSt", b"hidden");
+    let path = temp_png("verify-no-private", &bytes);
+    let output = pngme(&["verify", path.to_str().unwrap(), "--no-private", "--json"]);
+    assert!(!output.status.success());
+    let report = stdout_json(&output);
+    assert_eq!(report["errors"][0]["kind"], "private_chunk");
+
+    // What's left is the fixture's own RuSt chunk, private but critical
+    assert!(pngme(&["remove", path.to_str().unwrap(), "ruSt"])
+        .status
+        .success());
+    let output = pngme(&["verify", path.to_str().unwrap(), "--no-private"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_print_all_chunks() {
+    let path = temp_png("print-all", &fixture());
+    let output = pngme(&["print", path.to_str().unwrap()]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    for chunk_type in ["IHDR", "sRGB", "gAMA", "pHYs", "IDAT", "RuSt", "IEND"] {
+        assert!(stdout.contains(&format!("Chunk Type : {}", chunk_type)));
+    }
+}
+
+#[test]
+fn test_print_huge_declared_length() {
+    // A lone chunk header claiming almost 4 GB of data
+    let mut bytes = fixture()[..8].to_vec();
+    bytes.extend_from_slice(&0xFFFF_FFF0u32.to_be_bytes());
+    bytes.extend_from_slice(b"IHDR");
+    bytes.extend_from_slice(&[0; 8]);
+    let path = temp_png("print-huge-length", &bytes);
+
+    let output = pngme(&["print", path.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("error: "), "{}", stderr);
+}
+
+#[test]
+fn test_print_min_size() {
+    let path = temp_png("print-min-size", &fixture());
+    let output = pngme(&["print", path.to_str().unwrap(), "--min-size", "1024"]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("Chunk Type : ").count(), 1);
+    assert!(stdout.contains("Chunk Type : IDAT"));
+}
+
+#[test]
+fn test_print_size_range() {
+    let path = temp_png("print-size-range", &fixture());
+    let output = pngme(&[
+        "print",
+        path.to_str().unwrap(),
+        "--min-size",
+        "1",
+        "--max-size",
+        "9",
+    ]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("Chunk Type : ").count(), 4);
+    assert!(!stdout.contains("Chunk Type : IHDR"));
+    assert!(!stdout.contains("Chunk Type : IEND"));
+}
+
+#[test]
+fn test_decode_exact_chunk_type() {
+    let path = temp_png("decode-exact", &fixture());
+
+    let output = pngme(&["decode", path.to_str().unwrap(), "RuSt"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().contains("hey"));
+
+    let output = pngme(&["decode", path.to_str().unwrap(), "ruST"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_decode_ignore_flags() {
+    let path = temp_png("decode-ignore-flags", &fixture());
+    let output = pngme(&["decode", path.to_str().unwrap(), "ruST", "--ignore-flags"]);
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().contains("hey"));
+}
+
+#[test]
+fn test_decode_search() {
+    let bytes = with_chunk(fixture(), b"abCd", b"forgotten secret");
+    let path = temp_png("decode-search", &bytes);
+    let output = pngme(&["decode", path.to_str().unwrap(), "--search"]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("abCd: forgotten secret"));
+    assert!(!stdout.contains("IHDR"));
+
+    let output = pngme(&["decode", path.to_str().unwrap(), "RuSt", "--search"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_parsing_chunk_types_prints_nothing() {
+    let path = temp_png("quiet-parse", &fixture());
+
+    let output = pngme(&["decode", path.to_str().unwrap(), "RuSt"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "hey\n");
+
+    // Standard and private types the fixture doesn't have, only stderr may be written to
+    for chunk_type in [
+        "PLTE", "tRNS", "cHRM", "iCCP", "sBIT", "tEXt", "zTXt", "iTXt", "bKGD", "hIST", "sPLT",
+        "eXIf", "tIME", "oFFs", "ruSt", "prIv", "abCd", "XyZw", "meTa", "DaTa",
+    ] {
+        let output = pngme(&["decode", path.to_str().unwrap(), chunk_type]);
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty(), "{} wrote to stdout", chunk_type);
+    }
+
+    let output = pngme(&["remove", path.to_str().unwrap(), "rust", "--ignore-flags"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Removed RuSt\n");
+}
+
+#[test]
+fn test_remove_ignore_flags() {
+    let path = temp_png("remove-ignore-flags", &fixture());
+    let output = pngme(&["remove", path.to_str().unwrap(), "rust", "--ignore-flags"]);
+    assert!(output.status.success());
+
+    let output = pngme(&["print", path.to_str().unwrap()]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains("Chunk Type : RuSt"));
+    assert!(stdout.contains("Chunk Type : IEND"));
+}
+
+#[test]
+fn test_verify_json_reserved_bit_warning() {
+    let mut bytes = fixture();
+    // Lowercase the third letter of "RuSt" and fix up its crc
+    let start = bytes.windows(4).position(|w| w == b"RuSt").unwrap();
+    bytes[start + 2] = b's';
+    let crc = crc::crc32::checksum_ieee(&bytes[start..start + 4 + 3]);
+    bytes[start + 7..start + 11].copy_from_slice(&crc.to_be_bytes());
+    let path = temp_png("verify-json-reserved", &bytes);
+    let output = pngme(&["verify", path.to_str().unwrap(), "--json"]);
+
+    assert!(output.status.success());
+    let report = stdout_json(&output);
+    assert_eq!(report["valid"], true);
+    let warnings = report["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0]["kind"], "reserved_bit");
+}
+
+#[test]
+fn test_copy_text_chunk() {
+    let src = temp_png(
+        "copy-src",
+        &with_chunk(fixture(), b"tEXt", b"Author\0Lemon"),
+    );
+    let dst = temp_png("copy-dst", &fixture());
+    let output = pngme(&["copy", src.to_str().unwrap(), dst.to_str().unwrap(), "tEXt"]);
+    assert!(output.status.success());
+
+    let copied = fs::read(&dst).unwrap();
+    assert_eq!(copied, with_chunk(fixture(), b"tEXt", b"Author\0Lemon"));
+}
+
+#[test]
+fn test_copy_missing_chunk() {
+    let src = temp_png("copy-missing-src", &fixture());
+    let dst = temp_png("copy-missing-dst", &fixture());
+    let output = pngme(&["copy", src.to_str().unwrap(), dst.to_str().unwrap(), "tEXt"]);
+
+    assert!(!output.status.success());
+    assert_eq!(fs::read(&dst).unwrap(), fixture());
+}
+
+#[test]
+fn test_stats() {
+    let path = temp_png("stats", &fixture());
+    let output = pngme(&["stats", path.to_str().unwrap()]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Chunks : 7\n"));
+    assert!(stdout.contains(&format!("Size : {} bytes\n", fixture().len())));
+    assert!(stdout.contains("Color profile : yes\n"));
+    assert!(stdout.contains("RuSt : 1 chunk(s), 3 bytes\n"));
+}
+
+#[test]
+fn test_chunktype_bkgd() {
+    let output = pngme(&["chunktype", "bKGD"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Type : bKGD\n\
+         Valid : yes\n\
+         Critical : no\n\
+         Public : yes\n\
+         Reserved bit : valid\n\
+         Safe to copy : no\n\
+         Description : Background colour\n"
+    );
+
+    assert!(!pngme(&["chunktype", "bKG"]).status.success());
+}
+
+#[test]
+fn test_encode_decode_round_trip() {
+    let path = temp_png("encode-decode", &fixture());
+    let output_path = std::env::temp_dir().join(format!(
+        "pngmehd-{}-encode-decode-out.png",
+        std::process::id()
+    ));
+    let output = pngme(&[
+        "encode",
+        path.to_str().unwrap(),
+        "ruSt",
+        "Meet at dawn",
+        output_path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+
+    let output = pngme(&["decode", output_path.to_str().unwrap(), "ruSt"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Meet at dawn\n");
+    // The input is left alone when an output file is given
+    assert_eq!(fs::read(&path).unwrap(), fixture());
+}
+
+#[test]
+fn test_encode_remove_decode() {
+    let path = temp_png("encode-remove", &fixture());
+    assert!(
+        pngme(&["encode", path.to_str().unwrap(), "ruSt", "Gone soon"])
+            .status
+            .success()
+    );
+    assert!(pngme(&["remove", path.to_str().unwrap(), "ruSt"])
+        .status
+        .success());
+
+    let output = pngme(&["decode", path.to_str().unwrap(), "ruSt"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("error: No ruSt chunk in"));
+}
+
+#[test]
+fn test_encode_invalid_chunk_type() {
+    let path = temp_png("encode-invalid", &fixture());
+    let output = pngme(&["encode", path.to_str().unwrap(), "12", "message"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Chunk types must be exactly 4 ASCII characters"));
+}